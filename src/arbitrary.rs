@@ -0,0 +1,33 @@
+//! Strategies for generating [`DynRatio`]s with [`proptest`], enabled by the `proptest` feature.
+//!
+//! [`DynRatio`]: ../struct.DynRatio.html
+//! [`proptest`]: https://docs.rs/proptest/
+
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use super::DynRatio;
+
+/// Returns a strategy which generates ratios whose unreduced numerator lies in
+/// `[-max_numer, max_numer]` and whose unreduced denominator lies in `[1, max_denom]`.
+///
+/// Values shrink toward zero and toward a denominator of one.
+///
+/// Panics if `max_numer` is negative or `max_denom` is not positive.
+pub fn ratios(max_numer: i64, max_denom: i64) -> BoxedStrategy<DynRatio> {
+    assert!(max_numer >= 0, "`max_numer` must be non-negative");
+    assert!(max_denom > 0, "`max_denom` must be positive");
+
+    (-max_numer..=max_numer, 1..=max_denom)
+        .prop_map(|(num, den)| DynRatio::new(num, den))
+        .boxed()
+}
+
+impl Arbitrary for DynRatio {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<DynRatio>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        ratios(i64::max_value(), i64::max_value())
+    }
+}
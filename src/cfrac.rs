@@ -0,0 +1,64 @@
+//! Simple continued fractions of ratios.
+
+use std::ops::{Add, Mul, Sub};
+
+use typenum::{
+    B0, B1,
+    Integer,
+    Z0,
+    array::{ATerm, TArr},
+    operator_aliases::*,
+    type_operators::*,
+};
+
+use super::{Ratio, Rational, private::*, type_operators::*};
+use super::operator_aliases::{Num, Den, EvalCFrac, Floor, Inverse};
+
+type CfRem<N, D> = Diff<N, Prod<Floor<Ratio<N, D>>, D>>;
+type CfTail<N, D> = <CfRem<N, D> as PrivateCFracTail<D, Eq<CfRem<N, D>, Z0>>>::Output;
+
+/// N/D = floor(N/D) + 1/(D/R) where R = N - floor(N/D)*D
+impl<N, D> PrivateContinuedFraction<D> for N
+    where Ratio<N, D>: IntegerFloor,
+          Floor<Ratio<N, D>>: Mul<D>,
+          N: Sub<Prod<Floor<Ratio<N, D>>, D>>,
+          CfRem<N, D>: IsEqual<Z0> + PrivateCFracTail<D, Eq<CfRem<N, D>, Z0>>,
+{
+    type Output = TArr<Floor<Ratio<N, D>>, CfTail<N, D>>;
+}
+
+/// The remainder is zero, so the expansion is complete.
+impl<R, D> PrivateCFracTail<D, B1> for R {
+    type Output = ATerm;
+}
+
+impl<R, D> PrivateCFracTail<D, B0> for R
+    where D: PrivateContinuedFraction<R>,
+{
+    type Output = <D as PrivateContinuedFraction<R>>::Output;
+}
+
+impl<N, D> ContinuedFractionExpansion for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: PrivateContinuedFraction<Den<N, D>>,
+{
+    type Output = <Num<N, D> as PrivateContinuedFraction<Den<N, D>>>::Output;
+}
+
+/// [a] = a
+impl<A> ContinuedFractionValue for TArr<A, ATerm>
+    where A: Integer,
+          Ratio<A>: Rational,
+{
+    type Output = Ratio<A>;
+}
+
+/// [a; b, ...] = a + 1/[b; ...]
+impl<A, B, Rest> ContinuedFractionValue for TArr<A, TArr<B, Rest>>
+    where A: Integer,
+          TArr<B, Rest>: ContinuedFractionValue,
+          EvalCFrac<TArr<B, Rest>>: Inv,
+          Ratio<A>: Add<Inverse<EvalCFrac<TArr<B, Rest>>>>,
+{
+    type Output = Sum<Ratio<A>, Inverse<EvalCFrac<TArr<B, Rest>>>>;
+}
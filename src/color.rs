@@ -0,0 +1,45 @@
+//! Helpers for mixing color channels using compile-time ratios.
+
+use typenum::Integer;
+
+use super::{Rational, private::UnitInterval};
+
+/// Blends two 8-bit channel values, computing `a * R + b * (1 - R)`.
+///
+/// The exact result is rounded to the nearest integer, with ties rounded up. A ratio outside of
+/// `[0, 1]` is rejected at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, color};
+///
+/// assert_eq!(color::blend::<Ratio<P1, P4>>(255, 0), 64);
+/// assert_eq!(color::blend::<Ratio<P1, P2>>(100, 201), 151);
+/// ```
+///
+/// ```rust,compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, color};
+///
+/// color::blend::<Ratio<N1, P4>>(255, 0);
+/// ```
+pub fn blend<R>(a: u8, b: u8) -> u8
+    where R: Rational,
+{
+    const_assert!(UnitInterval<R>);
+
+    let num = R::Num::to_i64();
+    let den = R::Den::to_i64();
+
+    // Intermediates are widened to `i64` since `den` may be arbitrarily large.
+    let sum = i64::from(a) * num + i64::from(b) * (den - num);
+    ((2 * sum + den) / (2 * den)) as u8
+}
@@ -43,6 +43,15 @@ use typenum::consts::*;
 
 use super::Ratio;
 
+pub mod approx;
+pub mod astro;
+pub mod map_scale;
+pub mod model_scale;
+pub mod sexagesimal;
+pub mod ui_scale;
+pub mod volume_uk;
+pub mod volume_us;
+
 /// 10¹⁸
 pub type Exa = Ratio<P1000000000000000000>;
 /// 10¹⁵
@@ -0,0 +1,23 @@
+//! Classic rational approximations of irrational constants.
+//!
+//! Each approximation is documented with its signed error, i.e. the approximation minus the true
+//! value.
+
+use typenum::consts::*;
+use typenum::operator_aliases::*;
+
+use Ratio;
+
+/// π ≈ 22/7, with an error of +1.26 × 10⁻³.
+pub type Pi22Over7 = Ratio<P22, P7>;
+/// π ≈ 333/106, with an error of −8.32 × 10⁻⁵.
+pub type Pi333Over106 = Ratio<P333, P106>;
+/// π ≈ 355/113, with an error of +2.67 × 10⁻⁷.
+pub type Pi355Over113 = Ratio<P355, P113>;
+
+/// e ≈ 19/7, with an error of −4.00 × 10⁻³.
+pub type E19Over7 = Ratio<P19, P7>;
+/// e ≈ 87/32, with an error of +4.68 × 10⁻⁴.
+pub type E87Over32 = Ratio<P87, P32>;
+/// e ≈ 2721/1001, with an error of −1.10 × 10⁻⁷.
+pub type E2721Over1001 = Ratio<Sum<Prod<P2, P1000>, P721>, Sum<P1000, P1>>;
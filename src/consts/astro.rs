@@ -0,0 +1,18 @@
+//! Astronomical units of time, relative to the SI second.
+
+use typenum::consts::*;
+use typenum::operator_aliases::*;
+
+use Ratio;
+
+/// 1 day = 86400 s
+pub type JulianDay = Ratio<Prod<P864, P100>>;
+/// 1 Julian year = 365.25 days = 31557600 s
+pub type JulianYear = Prod<JulianDay, Ratio<Sum<P1000, P461>, P4>>;
+/// 1 Julian century = 36525 days = 3155760000 s
+pub type JulianCentury = Prod<JulianYear, P100>;
+/// 1 mean sidereal day = 86164.09053 s, as published in the IERS numerical standards.
+pub type SiderealDay = Ratio<
+    Sum<Sum<Sum<Prod<P8, P1000000000>, Prod<P616, P1000000>>, Prod<P409, P1000>>, P53>,
+    P100000,
+>;
@@ -0,0 +1,15 @@
+//! Standard cartographic scales, expressed as the ratio of map distance to ground distance.
+
+use typenum::consts::*;
+use typenum::operator_aliases::*;
+
+use Ratio;
+
+/// 1:10 000
+pub type Scale1To10000 = Ratio<P1, P10000>;
+/// 1:25 000
+pub type Scale1To25000 = Ratio<P1, Prod<P25, P1000>>;
+/// 1:50 000
+pub type Scale1To50000 = Ratio<P1, Prod<P50, P1000>>;
+/// 1:100 000
+pub type Scale1To100000 = Ratio<P1, P100000>;
@@ -0,0 +1,16 @@
+//! Common scales used for scale models, expressed as the ratio of model size to prototype size.
+
+use typenum::consts::*;
+
+use Ratio;
+
+/// HO scale (1:87)
+pub type HoScale = Ratio<P1, P87>;
+/// N scale (1:160)
+pub type NScale = Ratio<P1, P160>;
+/// 1:72, common for aircraft
+pub type Scale1To72 = Ratio<P1, P72>;
+/// 1:35, common for military vehicles and figures
+pub type Scale1To35 = Ratio<P1, P35>;
+/// 1:24, common for cars and dollhouses
+pub type Scale1To24 = Ratio<P1, P24>;
@@ -0,0 +1,58 @@
+//! Sexagesimal subdivisions, for degree-minute-second angles and hour-minute-second times.
+//!
+//! Values are expressed in units of the whole degree or hour. Components are simply summed, so a
+//! negative value must be written with every component negated, e.g. −1°30′ is `Dms<N1, N30, Z0>`.
+//!
+//! # Example
+//!
+//! ```rust
+//! extern crate typenum;
+//! extern crate typenum_ratio;
+//!
+//! use typenum::consts::*;
+//! use typenum_ratio::{DynRatio, Ratio};
+//! use typenum_ratio::consts::sexagesimal::*;
+//!
+//! assert_eq!(Dms::<P1, P30, P36>::default(), Ratio::<P151, P100>::default());
+//! assert_eq!(from_sexagesimal(1, 30, DynRatio::from_integer(36)), DynRatio::new(151, 100));
+//! assert_eq!(to_sexagesimal(DynRatio::new(151, 100)), (1, 30, DynRatio::from_integer(36)));
+//! ```
+
+use typenum::consts::*;
+use typenum::operator_aliases::*;
+
+use {DynRatio, Ratio};
+
+/// 1/60, a minute of arc or of time
+pub type Sixtieth = Ratio<P1, P60>;
+/// 1/3600, a second of arc or of time
+pub type ThreeThousandSixHundredth = Ratio<P1, Prod<P60, P60>>;
+
+/// The value of `D`° `M`′ `S`″ in degrees.
+pub type Dms<D, M, S> = Sum<
+    Sum<Ratio<D>, Prod<Ratio<M>, Sixtieth>>,
+    Prod<Ratio<S>, ThreeThousandSixHundredth>,
+>;
+/// The value of `H`:`M`:`S` in hours.
+pub type Hms<H, M, S> = Dms<H, M, S>;
+
+/// Returns `whole + minutes/60 + seconds/3600`.
+///
+/// Panics if the result overflows a `DynRatio`.
+pub fn from_sexagesimal(whole: i64, minutes: i64, seconds: DynRatio) -> DynRatio {
+    DynRatio::from_integer(whole)
+        + DynRatio::new(minutes, 60)
+        + seconds * DynRatio::new(1, 3600)
+}
+
+/// Splits `x` into whole units, whole minutes and (possibly fractional) seconds.
+///
+/// Every component has the same sign as `x`, so `from_sexagesimal` is the inverse of this function.
+pub fn to_sexagesimal(x: DynRatio) -> (i64, i64, DynRatio) {
+    let whole = x.numer() / x.denom();
+    let minutes = x - DynRatio::from_integer(whole);
+    let minutes = minutes * DynRatio::from_integer(60);
+    let whole_minutes = minutes.numer() / minutes.denom();
+    let seconds = (minutes - DynRatio::from_integer(whole_minutes)) * DynRatio::from_integer(60);
+    (whole, whole_minutes, seconds)
+}
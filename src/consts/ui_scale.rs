@@ -0,0 +1,16 @@
+//! Common display scaling factors used by desktop environments for HiDPI screens.
+
+use typenum::consts::*;
+
+use Ratio;
+
+/// 125%
+pub type Scale125 = Ratio<P5, P4>;
+/// 150%
+pub type Scale150 = Ratio<P3, P2>;
+/// 175%
+pub type Scale175 = Ratio<P7, P4>;
+/// 200%
+pub type Scale200 = Ratio<P2>;
+/// 225%
+pub type Scale225 = Ratio<P9, P4>;
@@ -0,0 +1,17 @@
+//! Imperial units of fluid volume, relative to the litre.
+//!
+//! These are **not** interchangeable with the [US customary units][us] of the same name.
+//!
+//! [us]: ../volume_us/index.html
+
+use typenum::consts::*;
+use typenum::operator_aliases::*;
+
+use Ratio;
+
+/// 1 imperial gallon = 4.54609 L
+pub type ImperialGallon = Ratio<Sum<Prod<P454, P1000>, P609>, P100000>;
+/// 1 imperial pint = 1/8 imperial gallon
+pub type ImperialPint = Quot<ImperialGallon, P8>;
+/// 1 imperial fluid ounce = 1/160 imperial gallon
+pub type ImperialFluidOunce = Quot<ImperialGallon, P160>;
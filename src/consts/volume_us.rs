@@ -0,0 +1,20 @@
+//! United States customary units of fluid volume, relative to the litre.
+//!
+//! These are **not** interchangeable with the [imperial units][uk] of the same name.
+//!
+//! [uk]: ../volume_uk/index.html
+
+use typenum::consts::*;
+use typenum::operator_aliases::*;
+
+use Ratio;
+
+/// 1 US gallon = 231 cubic inches = 3.785411784 L
+pub type UsGallon = Ratio<
+    Sum<Sum<Sum<Prod<P3, P1000000000>, Prod<P785, P1000000>>, Prod<P411, P1000>>, P784>,
+    P1000000000,
+>;
+/// 1 US pint = 1/8 US gallon
+pub type UsPint = Quot<UsGallon, P8>;
+/// 1 US fluid ounce = 1/128 US gallon
+pub type UsFluidOunce = Quot<UsGallon, P128>;
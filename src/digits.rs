@@ -0,0 +1,120 @@
+use std::fmt;
+use std::ops::{Div, Rem};
+
+use typenum::{
+    B0, B1, Bit,
+    NInt, NonZero, PInt,
+    UInt, UTerm,
+    Unsigned, Z0,
+    consts::{U0, U1, U5},
+    operator_aliases::*,
+    type_operators::IsEqual,
+};
+
+use super::{Ratio, Rational, type_operators::TerminatingDecimal};
+use super::operator_aliases::Den;
+use super::private::{
+    PrivateBits, PrivateDecimal, PrivateMagnitude, PrivateOddPart, PrivateWithoutFactor,
+    PrivateWithoutFactorIf,
+};
+
+impl PrivateBits for UTerm {
+    fn push_bits(_: &mut Vec<bool>) {}
+}
+
+impl<U, B> PrivateBits for UInt<U, B>
+    where U: Unsigned + PrivateBits,
+          B: Bit,
+{
+    fn push_bits(bits: &mut Vec<bool>) {
+        U::push_bits(bits);
+        bits.push(B::to_bool());
+    }
+}
+
+impl PrivateDecimal for Z0 {
+    fn write_decimal(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0")
+    }
+}
+
+impl<U> PrivateDecimal for PInt<U>
+    where U: Unsigned + NonZero + PrivateBits,
+{
+    fn write_decimal(f: &mut fmt::Formatter) -> fmt::Result {
+        write_unsigned::<U>(f)
+    }
+}
+
+impl<U> PrivateDecimal for NInt<U>
+    where U: Unsigned + NonZero + PrivateBits,
+{
+    fn write_decimal(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "-")?;
+        write_unsigned::<U>(f)
+    }
+}
+
+impl<U, F> PrivateWithoutFactor<F> for U
+    where U: Rem<F>,
+          Mod<U, F>: IsEqual<U0>,
+          U: PrivateWithoutFactorIf<F, Eq<Mod<U, F>, U0>>,
+{
+    type Output = <U as PrivateWithoutFactorIf<F, Eq<Mod<U, F>, U0>>>::Output;
+}
+
+impl<U, F> PrivateWithoutFactorIf<F, B0> for U {
+    type Output = U;
+}
+
+impl<U, F> PrivateWithoutFactorIf<F, B1> for U
+    where U: Div<F>,
+          Quot<U, F>: PrivateWithoutFactor<F>,
+{
+    type Output = <Quot<U, F> as PrivateWithoutFactor<F>>::Output;
+}
+
+type DenMag<N, D> = <Den<N, D> as PrivateMagnitude>::Output;
+type WithoutTwosAndFives<U> = <<U as PrivateOddPart>::Output as PrivateWithoutFactor<U5>>::Output;
+
+/// 10 = 2*5, so only a denominator with no other prime factors terminates
+impl<N, D> TerminatingDecimal for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Den<N, D>: PrivateMagnitude,
+          DenMag<N, D>: PrivateOddPart,
+          <DenMag<N, D> as PrivateOddPart>::Output: PrivateWithoutFactor<U5>,
+          WithoutTwosAndFives<DenMag<N, D>>: IsEqual<U1>,
+{
+    type Output = Eq<WithoutTwosAndFives<DenMag<N, D>>, U1>;
+}
+
+/// Writes `U` in decimal, supporting values which do not fit in any primitive integer.
+fn write_unsigned<U: PrivateBits>(f: &mut fmt::Formatter) -> fmt::Result {
+    const BASE: u64 = 1_000_000_000;
+
+    let mut bits = Vec::new();
+    U::push_bits(&mut bits);
+
+    // Little-endian limbs in base 10^9.
+    let mut limbs = vec![0u32];
+    for bit in bits {
+        let mut carry = bit as u64;
+        for limb in &mut limbs {
+            let v = u64::from(*limb) * 2 + carry;
+            *limb = (v % BASE) as u32;
+            carry = v / BASE;
+        }
+
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+    }
+
+    let mut limbs = limbs.iter().rev();
+    write!(f, "{}", limbs.next().unwrap())?;
+    for limb in limbs {
+        write!(f, "{:09}", limb)?;
+    }
+
+    Ok(())
+}
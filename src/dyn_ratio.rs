@@ -0,0 +1,611 @@
+use std::{cmp, fmt};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::ops::*;
+
+use typenum::{Integer, NonZero, Unsigned};
+
+use super::{Rational, Ratio};
+
+/// A rational number whose value is only known at runtime.
+///
+/// `DynRatio` is the runtime counterpart of [`Ratio`]. It does not implement [`Rational`], but
+/// follows the same rules: it is always stored as a [reduced fraction][reduced] with a positive
+/// denominator, so two `DynRatio`s are equal if and only if their numerators and denominators are
+/// equal.
+///
+/// Arithmetic is exact. Intermediate results are computed using 128-bit integers, and an operation
+/// whose reduced result does not fit in an `i64` will panic.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate typenum_ratio;
+/// extern crate typenum;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::DynRatio;
+///
+/// assert_eq!(DynRatio::from(rat!(P3/P6)), DynRatio::new(1, 2));
+/// assert_eq!(DynRatio::new(1, 3) + DynRatio::new(1, 6), DynRatio::new(1, 2));
+/// ```
+///
+/// [`Ratio`]: ./struct.Ratio.html
+/// [`Rational`]: ./trait.Rational.html
+/// [reduced]: http://mathworld.wolfram.com/ReducedFraction.html
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynRatio {
+    num: i64,
+    den: i64,
+}
+
+impl DynRatio {
+    /// Constructs a new `DynRatio` from the reduced form of `num/den`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero.
+    pub fn new(num: i64, den: i64) -> Self {
+        DynRatio::from_i128(num.into(), den.into())
+    }
+
+    /// Constructs a new `DynRatio` equal to the integer `n`.
+    pub fn from_integer(n: i64) -> Self {
+        DynRatio { num: n, den: 1 }
+    }
+
+    /// The numerator of the reduced fraction.
+    pub fn numer(&self) -> i64 {
+        self.num
+    }
+
+    /// The denominator of the reduced fraction.
+    ///
+    /// This is always positive.
+    pub fn denom(&self) -> i64 {
+        self.den
+    }
+
+    /// Returns the value of `self` as an integer if it has no fractional part.
+    pub fn to_integer(&self) -> Option<i64> {
+        if self.den == 1 {
+            Some(self.num)
+        } else {
+            None
+        }
+    }
+
+    /// Converts `self` to the nearest `f64`.
+    pub fn to_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+
+    /// Converts `self` to the nearest `f64`, also returning the exact rounding error.
+    ///
+    /// The error is returned as a pair `(err, exp)` such that `self == x + err * 2^exp`, where `x`
+    /// is the returned float. `exp` is the exponent of the unit in the last place of `x`, so `err`
+    /// always lies in the range `[-1/2, 1/2]` and never overflows a `DynRatio`. Scaling `err` back
+    /// into a single `DynRatio` is not always possible: the error of `1/100` is `-3/(25 * 2^59)`,
+    /// whose denominator does not fit in an `i64`. If the float is exact, the error is `(0, 0)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use typenum_ratio::DynRatio;
+    ///
+    /// assert_eq!(DynRatio::new(3, 4).to_f64_with_error(), (0.75, (DynRatio::default(), 0)));
+    ///
+    /// // 1/10 = 0.1 - (2/5) * 2^-56
+    /// let (x, err) = DynRatio::new(1, 10).to_f64_with_error();
+    /// assert_eq!(x, 0.1);
+    /// assert_eq!(err, (DynRatio::new(-2, 5), -56));
+    /// ```
+    pub fn to_f64_with_error(&self) -> (f64, (DynRatio, i32)) {
+        let num = i128::from(self.num);
+        let den = i128::from(self.den);
+
+        // `to_f64` rounds `num` and `den` before dividing, so it can be an ulp or two away from
+        // the nearest float. Step towards `self` until the error is at most half an ulp.
+        let mut x = self.to_f64();
+        loop {
+            // Decompose `x` into `mant * 2^exp`, where `mant` has all 53 bits of precision.
+            let bits = x.to_bits();
+            let biased = ((bits >> 52) & 0x7ff) as i32;
+            let frac = i128::from(bits & ((1 << 52) - 1));
+            let (mut mant, exp) = if biased == 0 {
+                (frac, -1074)
+            } else {
+                (frac | 1 << 52, biased - 1075)
+            };
+
+            if bits >> 63 == 1 {
+                mant = -mant;
+            }
+
+            // (num/den - mant*2^exp) / 2^exp = err_num/err_den
+            let (err_num, err_den) = if exp >= 0 {
+                // `x` is at least 2^53, so `den` is small enough for `den*2^exp` not to overflow.
+                (num - (mant * den << exp), den << exp)
+            } else {
+                // `num*2^-exp` may overflow an `i128`, but the difference is only a few multiples
+                // of `den`, so it is recovered exactly by wrapping arithmetic.
+                let shift = (-exp) as u32;
+                let scaled = if shift < 128 { num.wrapping_shl(shift) } else { 0 };
+                (scaled.wrapping_sub(mant * den), den)
+            };
+
+            if 2 * err_num.abs() <= err_den {
+                return if err_num == 0 {
+                    (x, (DynRatio::default(), 0))
+                } else {
+                    (x, (DynRatio::from_i128(err_num, err_den), exp))
+                };
+            }
+
+            // `x` is never zero here, since only a zero ratio rounds to zero.
+            x = f64::from_bits(if (err_num > 0) == (x > 0.0) { bits + 1 } else { bits - 1 });
+        }
+    }
+
+    /// Renders the exact expansion of `self` in the given base.
+    ///
+    /// At most `max_digits` digits are produced after the radix point. A repeating expansion is
+    /// written with the repetend in parentheses, and an expansion which neither terminates nor
+    /// repeats within `max_digits` digits is truncated and followed by `...`. If `max_digits` is
+    /// zero, only the integer part is written, followed by `...` unless `self` is an integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is not in the range `2..=36`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use typenum_ratio::DynRatio;
+    ///
+    /// assert_eq!(DynRatio::new(5, 8).to_string_radix(2, 10), "0.101");
+    /// assert_eq!(DynRatio::new(-1, 3).to_string_radix(10, 10), "-0.(3)");
+    /// assert_eq!(DynRatio::new(1, 10).to_string_radix(2, 10), "0.0(0011)");
+    /// assert_eq!(DynRatio::new(1, 7).to_string_radix(10, 3), "0.142...");
+    /// assert_eq!(DynRatio::new(7, 2).to_string_radix(10, 0), "3...");
+    /// ```
+    pub fn to_string_radix(&self, base: u32, max_digits: usize) -> String {
+        assert!((2..=36).contains(&base), "base must be in the range 2..=36");
+
+        let digit = |d: i128| ::std::char::from_digit(d as u32, base).unwrap();
+        let base = i128::from(base);
+        let num = i128::from(self.num).abs();
+        let den = i128::from(self.den);
+
+        let mut s = String::new();
+        if self.num < 0 {
+            s.push('-');
+        }
+
+        let mut int = num / den;
+        let mut int_digits = vec![digit(int % base)];
+        int /= base;
+        while int != 0 {
+            int_digits.push(digit(int % base));
+            int /= base;
+        }
+        s.extend(int_digits.iter().rev());
+
+        let mut rem = num % den;
+        if rem == 0 {
+            return s;
+        }
+
+        if max_digits == 0 {
+            s.push_str("...");
+            return s;
+        }
+
+        // Maps each remainder to the index of the fractional digit it produced.
+        let mut seen = HashMap::new();
+        let mut frac = String::new();
+        while rem != 0 && frac.len() < max_digits {
+            if let Some(&start) = seen.get(&rem) {
+                frac.insert(start, '(');
+                frac.push(')');
+                rem = 0;
+                break;
+            }
+
+            seen.insert(rem, frac.len());
+            rem *= base;
+            frac.push(digit(rem / den));
+            rem %= den;
+        }
+
+        // The last digit may complete a cycle exactly at the digit limit.
+        if rem != 0 {
+            if let Some(&start) = seen.get(&rem) {
+                frac.insert(start, '(');
+                frac.push(')');
+            } else {
+                frac.push_str("...");
+            }
+        }
+
+        s.push('.');
+        s.push_str(&frac);
+        s
+    }
+
+    /// Returns an object which displays `self` as a percentage with exactly `places` decimal
+    /// places.
+    ///
+    /// The last digit is rounded to nearest, with ties rounded away from zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use typenum_ratio::DynRatio;
+    ///
+    /// assert_eq!(DynRatio::new(7, 10).display_percent(1).to_string(), "70.0%");
+    /// assert_eq!(DynRatio::new(2, 3).display_percent(2).to_string(), "66.67%");
+    /// assert_eq!(DynRatio::new(-1, 8).display_percent(0).to_string(), "-13%");
+    /// ```
+    pub fn display_percent(&self, places: usize) -> DisplayPercent {
+        DisplayPercent { ratio: *self, places }
+    }
+
+    /// Reduces `num/den` and stores it in a `DynRatio`.
+    ///
+    /// Panics if `den` is zero or if the reduced fraction does not fit in an `i64`.
+    pub(crate) fn from_i128(num: i128, den: i128) -> Self {
+        assert!(den != 0, "denominator of a `DynRatio` must be non-zero");
+        DynRatio::checked_from_i128(num, den).expect("`DynRatio` overflow")
+    }
+
+    /// Reduces `num/den` and stores it in a `DynRatio`, or returns `None` if the reduced fraction
+    /// does not fit in an `i64`.
+    ///
+    /// `den` must be non-zero.
+    pub(crate) fn checked_from_i128(num: i128, den: i128) -> Option<Self> {
+        let g = gcd(num, den);
+        let (mut num, mut den) = (num / g, den / g);
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+
+        if num >= i128::from(i64::min_value()) && num <= i128::from(i64::max_value())
+            && den <= i128::from(i64::max_value())
+        {
+            Some(DynRatio { num: num as i64, den: den as i64 })
+        } else {
+            None
+        }
+    }
+}
+
+/// The greatest common divisor of `a` and `b`, which is always positive unless both are zero.
+pub(crate) fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    if a == 0 { 1 } else { a.abs() }
+}
+
+impl Default for DynRatio {
+    fn default() -> Self {
+        DynRatio::from_integer(0)
+    }
+}
+
+impl<N, D> From<Ratio<N, D>> for DynRatio
+    where Ratio<N, D>: Rational,
+{
+    fn from(_: Ratio<N, D>) -> Self {
+        DynRatio {
+            num: <Ratio<N, D> as Rational>::Num::to_i64(),
+            den: <Ratio<N, D> as Rational>::Den::to_i64(),
+        }
+    }
+}
+
+impl From<i64> for DynRatio {
+    fn from(n: i64) -> Self {
+        DynRatio::from_integer(n)
+    }
+}
+
+impl PartialOrd for DynRatio {
+    fn partial_cmp(&self, rhs: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+/// N1/D1 < N2/D2 iff N1*D2 < N2*D1
+impl Ord for DynRatio {
+    fn cmp(&self, rhs: &Self) -> cmp::Ordering {
+        let lhs = i128::from(self.num) * i128::from(rhs.den);
+        let rhs = i128::from(rhs.num) * i128::from(self.den);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl Add for DynRatio {
+    type Output = DynRatio;
+
+    fn add(self, rhs: DynRatio) -> DynRatio {
+        DynRatio::from_i128(
+            i128::from(self.num) * i128::from(rhs.den) + i128::from(rhs.num) * i128::from(self.den),
+            i128::from(self.den) * i128::from(rhs.den))
+    }
+}
+
+impl Sub for DynRatio {
+    type Output = DynRatio;
+
+    fn sub(self, rhs: DynRatio) -> DynRatio {
+        DynRatio::from_i128(
+            i128::from(self.num) * i128::from(rhs.den) - i128::from(rhs.num) * i128::from(self.den),
+            i128::from(self.den) * i128::from(rhs.den))
+    }
+}
+
+impl Mul for DynRatio {
+    type Output = DynRatio;
+
+    fn mul(self, rhs: DynRatio) -> DynRatio {
+        DynRatio::from_i128(
+            i128::from(self.num) * i128::from(rhs.num),
+            i128::from(self.den) * i128::from(rhs.den))
+    }
+}
+
+impl Div for DynRatio {
+    type Output = DynRatio;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: DynRatio) -> DynRatio {
+        DynRatio::from_i128(
+            i128::from(self.num) * i128::from(rhs.den),
+            i128::from(self.den) * i128::from(rhs.num))
+    }
+}
+
+impl Neg for DynRatio {
+    type Output = DynRatio;
+
+    fn neg(self) -> DynRatio {
+        DynRatio::from_i128(-i128::from(self.num), self.den.into())
+    }
+}
+
+impl fmt::Debug for DynRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl fmt::Display for DynRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+/// Displays a rational number as an exact percentage.
+///
+/// This `struct` is created by [`DynRatio::display_percent`] and [`Ratio::display_percent`].
+///
+/// [`DynRatio::display_percent`]: ./struct.DynRatio.html#method.display_percent
+/// [`Ratio::display_percent`]: ./struct.Ratio.html#method.display_percent
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayPercent {
+    ratio: DynRatio,
+    places: usize,
+}
+
+impl fmt::Display for DisplayPercent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let num = i128::from(self.ratio.num) * 100;
+        let den = i128::from(self.ratio.den);
+
+        // Long division, keeping the final remainder to decide the rounding direction.
+        let mut int = num.abs() / den;
+        let mut rem = num.abs() % den;
+        let mut digits = Vec::with_capacity(self.places);
+        for _ in 0..self.places {
+            rem *= 10;
+            digits.push((rem / den) as u8);
+            rem %= den;
+        }
+
+        if 2 * rem >= den {
+            let mut carry = true;
+            for d in digits.iter_mut().rev() {
+                if *d == 9 {
+                    *d = 0;
+                } else {
+                    *d += 1;
+                    carry = false;
+                    break;
+                }
+            }
+
+            if carry {
+                int += 1;
+            }
+        }
+
+        if num < 0 && (int != 0 || digits.iter().any(|&d| d != 0)) {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", int)?;
+        if !digits.is_empty() {
+            write!(f, ".")?;
+            for d in digits {
+                write!(f, "{}", d)?;
+            }
+        }
+
+        write!(f, "%")
+    }
+}
+
+/// An iterator over the [Farey sequence][farey] of order `N`.
+///
+/// Yields every reduced fraction in the closed interval `[0, 1]` whose denominator does not exceed
+/// `N`, in ascending order.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{DynRatio, FareySequence};
+///
+/// let f3: Vec<_> = FareySequence::<U3>::new().collect();
+/// assert_eq!(f3, vec![DynRatio::new(0, 1), DynRatio::new(1, 3), DynRatio::new(1, 2),
+///                     DynRatio::new(2, 3), DynRatio::new(1, 1)]);
+/// ```
+///
+/// [farey]: http://mathworld.wolfram.com/FareySequence.html
+pub struct FareySequence<N> {
+    // The next two terms of the sequence, `a/b` and `c/d`.
+    a: i64,
+    b: i64,
+    c: i64,
+    d: i64,
+    done: bool,
+    _order: PhantomData<N>,
+}
+
+impl<N> FareySequence<N>
+    where N: Unsigned + NonZero,
+{
+    /// Constructs an iterator positioned at the first term, `0/1`.
+    pub fn new() -> Self {
+        FareySequence {
+            a: 0,
+            b: 1,
+            c: 1,
+            d: N::to_i64(),
+            done: false,
+            _order: PhantomData,
+        }
+    }
+}
+
+impl<N> Default for FareySequence<N>
+    where N: Unsigned + NonZero,
+{
+    fn default() -> Self {
+        FareySequence::new()
+    }
+}
+
+impl<N> Iterator for FareySequence<N>
+    where N: Unsigned + NonZero,
+{
+    type Item = DynRatio;
+
+    fn next(&mut self) -> Option<DynRatio> {
+        if self.done {
+            return None;
+        }
+
+        let term = DynRatio { num: self.a, den: self.b };
+        if self.a == 1 && self.b == 1 {
+            self.done = true;
+            return Some(term);
+        }
+
+        // a/b, c/d => c/d, (k*c - a)/(k*d - b) where k = (N + b) / d
+        let k = (N::to_i64() + self.b) / self.d;
+        let (c, d) = (k * self.c - self.a, k * self.d - self.b);
+        self.a = self.c;
+        self.b = self.d;
+        self.c = c;
+        self.d = d;
+
+        Some(term)
+    }
+}
+
+/// An inclusive range of rational numbers with a rational step, all known at compile time.
+///
+/// Each value is computed as `Start + i * Step`, so no error accumulates over the course of the
+/// iteration. The range includes `End` if it is reachable, and is empty if `End` lies in the
+/// opposite direction of `Step`.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{DynRatio, Ratio, RatioRange};
+///
+/// let ticks: Vec<_> = RatioRange::<Ratio<Z0>, Ratio<P1>, Ratio<P1, P3>>::iter().collect();
+/// assert_eq!(ticks, vec![DynRatio::new(0, 1), DynRatio::new(1, 3),
+///                        DynRatio::new(2, 3), DynRatio::new(1, 1)]);
+/// ```
+pub struct RatioRange<Start, End, Step>(PhantomData<(Start, End, Step)>);
+
+impl<Start, End, Step> RatioRange<Start, End, Step>
+    where Start: Rational,
+          End: Rational,
+          Step: Rational,
+{
+    /// Returns an iterator over the values in the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Step` is zero.
+    pub fn iter() -> RatioRangeIter {
+        let step = DynRatio::new(Step::Num::to_i64(), Step::Den::to_i64());
+        assert!(step != DynRatio::default(), "step of a `RatioRange` must be non-zero");
+
+        RatioRangeIter {
+            start: DynRatio::new(Start::Num::to_i64(), Start::Den::to_i64()),
+            end: DynRatio::new(End::Num::to_i64(), End::Den::to_i64()),
+            step,
+            i: 0,
+        }
+    }
+}
+
+/// An iterator over the values in a [`RatioRange`].
+///
+/// [`RatioRange`]: ./struct.RatioRange.html
+#[derive(Clone, Debug)]
+pub struct RatioRangeIter {
+    start: DynRatio,
+    end: DynRatio,
+    step: DynRatio,
+    i: i64,
+}
+
+impl Iterator for RatioRangeIter {
+    type Item = DynRatio;
+
+    fn next(&mut self) -> Option<DynRatio> {
+        let value = self.start + DynRatio::from_integer(self.i) * self.step;
+        let in_range = if self.step > DynRatio::default() {
+            value <= self.end
+        } else {
+            value >= self.end
+        };
+
+        if in_range {
+            self.i += 1;
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
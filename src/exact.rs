@@ -0,0 +1,77 @@
+use typenum::Integer;
+
+use super::{DynRatio, Rational};
+
+/// The result of a division by a ratio which was not exact.
+///
+/// The dividend is equal to `quotient * R + remainder`, where `quotient` is rounded toward zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Remainder {
+    /// The quotient, rounded toward zero.
+    pub quotient: i64,
+    /// The non-zero remainder, which has the same sign as the dividend.
+    pub remainder: DynRatio,
+}
+
+/// An extension trait for dividing primitive integers by a compile-time ratio without losing
+/// information.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{DivExact, DynRatio, Ratio, Remainder};
+///
+/// assert_eq!(9i64.div_exact::<Ratio<P3, P2>>(), Ok(6));
+/// assert_eq!(10i64.div_exact::<Ratio<P3, P2>>(),
+///            Err(Remainder { quotient: 6, remainder: DynRatio::new(1, 1) }));
+/// ```
+pub trait DivExact {
+    /// Divides `self` by `R`, returning the quotient only if it is an integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is zero, or if the quotient does not fit in an `i64`. The quotient can be
+    /// larger in magnitude than `self` when `|R| < 1`, e.g. `i64::MAX.div_exact::<Ratio<P1, P2>>()`,
+    /// and a `u64` or `usize` above `i64::MAX` divided by a ratio of magnitude one or less never
+    /// fits.
+    fn div_exact<R: Rational>(self) -> Result<i64, Remainder>;
+}
+
+macro_rules! impl_div_exact {
+    ($($t:ty),*) => {$(
+        impl DivExact for $t {
+            fn div_exact<R: Rational>(self) -> Result<i64, Remainder> {
+                // x / (N/D) = (x*D)/N
+                let num = i128::from(R::Num::to_i64());
+                let den = i128::from(R::Den::to_i64());
+                assert!(num != 0, "attempt to divide by a zero ratio");
+
+                // Every implementing type fits in an `i128`, so the cast is lossless. `i128` has no
+                // `From<usize>` or `From<isize>`, since those types are platform-dependent.
+                let dividend = (self as i128) * den;
+                let quotient = dividend / num;
+                let rem = dividend % num;
+
+                // The quotient is at most `self * den` in magnitude, so may not fit in an `i64`.
+                assert!(quotient >= i128::from(i64::min_value())
+                            && quotient <= i128::from(i64::max_value()),
+                        "quotient does not fit in an `i64`");
+
+                if rem == 0 {
+                    Ok(quotient as i64)
+                } else {
+                    Err(Remainder {
+                        quotient: quotient as i64,
+                        remainder: DynRatio::from_i128(rem, den),
+                    })
+                }
+            }
+        }
+    )*}
+}
+
+impl_div_exact!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
@@ -0,0 +1,103 @@
+//! Exact representability of ratios as binary floating-point numbers.
+
+use std::ops::BitAnd;
+
+use typenum::{
+    B0, B1, Bit,
+    NInt, PInt, Z0,
+    NonZero,
+    UInt, UTerm,
+    Unsigned,
+    consts::{U1, U24, U53, U75, U128, U1024},
+    operator_aliases::*,
+    type_operators::*,
+};
+
+use super::{Ratio, Rational, private::*, type_operators::*};
+use super::operator_aliases::{Num, Den};
+
+impl PrivateFloatFormat for f32 {
+    type Precision = U24;
+    type MaxBits = U128;
+    // 2^-149
+    type MinBits = Sum<U75, U75>;
+}
+
+impl PrivateFloatFormat for f64 {
+    type Precision = U53;
+    type MaxBits = U1024;
+    // 2^-1074
+    type MinBits = Sum<U1024, Sum<U1, U75>>;
+}
+
+impl PrivateMagnitude for Z0 {
+    type Output = UTerm;
+}
+
+impl<U: Unsigned + NonZero> PrivateMagnitude for PInt<U> {
+    type Output = U;
+}
+
+impl<U: Unsigned + NonZero> PrivateMagnitude for NInt<U> {
+    type Output = U;
+}
+
+impl PrivateOddPart for UTerm {
+    type Output = UTerm;
+}
+
+impl<U: Unsigned> PrivateOddPart for UInt<U, B1> {
+    type Output = UInt<U, B1>;
+}
+
+impl<U: Unsigned + PrivateOddPart> PrivateOddPart for UInt<U, B0> {
+    type Output = <U as PrivateOddPart>::Output;
+}
+
+type Mag<N, D> = <Num<N, D> as PrivateMagnitude>::Output;
+type DenMag<N, D> = <Den<N, D> as PrivateMagnitude>::Output;
+type OddPart<U> = <U as PrivateOddPart>::Output;
+
+/// Only a power-of-two denominator has no odd part
+impl<N, D> DyadicRatio for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Den<N, D>: PrivateMagnitude,
+          DenMag<N, D>: PrivateOddPart,
+          OddPart<DenMag<N, D>>: IsEqual<U1>,
+{
+    type Output = Eq<OddPart<DenMag<N, D>>, U1>;
+}
+
+// The denominator is a power of two and the significand fits in the precision of `F`.
+type IsExactSignificand<N, D, F> = And<
+    Eq<OddPart<DenMag<N, D>>, U1>,
+    LeEq<Length<OddPart<Mag<N, D>>>, <F as PrivateFloatFormat>::Precision>,
+>;
+
+// The value is neither too large nor too small for `F`.
+type IsInRange<N, D, F> = And<
+    LeEq<Length<Mag<N, D>>, <F as PrivateFloatFormat>::MaxBits>,
+    LeEq<Length<DenMag<N, D>>, <F as PrivateFloatFormat>::MinBits>,
+>;
+
+/// Only a power-of-two denominator can be represented exactly
+impl<N, D, F> ExactlyRepresentable<F> for Ratio<N, D>
+    where F: PrivateFloatFormat,
+          Ratio<N, D>: Rational,
+          Num<N, D>: PrivateMagnitude,
+          Den<N, D>: PrivateMagnitude,
+          Mag<N, D>: PrivateOddPart + Len,
+          DenMag<N, D>: PrivateOddPart + Len,
+          OddPart<Mag<N, D>>: Len,
+          OddPart<DenMag<N, D>>: IsEqual<U1>,
+          Length<OddPart<Mag<N, D>>>: IsLessOrEqual<F::Precision>,
+          Length<Mag<N, D>>: IsLessOrEqual<F::MaxBits>,
+          Length<DenMag<N, D>>: IsLessOrEqual<F::MinBits>,
+          Eq<OddPart<DenMag<N, D>>, U1>:
+              BitAnd<LeEq<Length<OddPart<Mag<N, D>>>, F::Precision>>,
+          LeEq<Length<Mag<N, D>>, F::MaxBits>: BitAnd<LeEq<Length<DenMag<N, D>>, F::MinBits>>,
+          IsExactSignificand<N, D, F>: BitAnd<IsInRange<N, D, F>>,
+          And<IsExactSignificand<N, D, F>, IsInRange<N, D, F>>: Bit,
+{
+    type Output = And<IsExactSignificand<N, D, F>, IsInRange<N, D, F>>;
+}
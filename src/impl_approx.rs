@@ -0,0 +1,87 @@
+//! Approximate comparisons between [`Ratio`] and floats, enabled by the `approx` feature.
+//!
+//! The ratio is converted to the nearest float before being compared, so tolerances behave exactly
+//! as they would when comparing two floats.
+//!
+//! [`Ratio`]: ../struct.Ratio.html
+
+use approx::{AbsDiffEq, RelativeEq};
+
+use super::{Ratio, Rational};
+
+macro_rules! impl_approx {
+    ($($t:ident),*) => {$(
+        /// A float is equal to a ratio only if it represents that ratio exactly.
+        impl<N, D> PartialEq<Ratio<N, D>> for $t
+            where Ratio<N, D>: Rational,
+        {
+            fn eq(&self, _: &Ratio<N, D>) -> bool {
+                let (value, (error, _)) = Ratio::<N, D>::default().to_f64_with_error();
+                error.numer() == 0 && value == f64::from(*self)
+            }
+        }
+
+        impl<N, D> PartialEq<$t> for Ratio<N, D>
+            where Ratio<N, D>: Rational,
+        {
+            fn eq(&self, rhs: &$t) -> bool {
+                *rhs == *self
+            }
+        }
+
+        impl<N, D> AbsDiffEq<Ratio<N, D>> for $t
+            where Ratio<N, D>: Rational,
+        {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                <$t as AbsDiffEq>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, _: &Ratio<N, D>, epsilon: $t) -> bool {
+                self.abs_diff_eq(&(Ratio::<N, D>::default().to_f64_with_error().0 as $t), epsilon)
+            }
+        }
+
+        impl<N, D> AbsDiffEq<$t> for Ratio<N, D>
+            where Ratio<N, D>: Rational,
+        {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                <$t as AbsDiffEq>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, rhs: &$t, epsilon: $t) -> bool {
+                rhs.abs_diff_eq(self, epsilon)
+            }
+        }
+
+        impl<N, D> RelativeEq<Ratio<N, D>> for $t
+            where Ratio<N, D>: Rational,
+        {
+            fn default_max_relative() -> $t {
+                <$t as RelativeEq>::default_max_relative()
+            }
+
+            fn relative_eq(&self, _: &Ratio<N, D>, epsilon: $t, max_relative: $t) -> bool {
+                let value = Ratio::<N, D>::default().to_f64_with_error().0 as $t;
+                self.relative_eq(&value, epsilon, max_relative)
+            }
+        }
+
+        impl<N, D> RelativeEq<$t> for Ratio<N, D>
+            where Ratio<N, D>: Rational,
+        {
+            fn default_max_relative() -> $t {
+                <$t as RelativeEq>::default_max_relative()
+            }
+
+            fn relative_eq(&self, rhs: &$t, epsilon: $t, max_relative: $t) -> bool {
+                rhs.relative_eq(self, epsilon, max_relative)
+            }
+        }
+    )*}
+}
+
+impl_approx!(f32, f64);
@@ -0,0 +1,51 @@
+//! Conversions into [`rug::Rational`], enabled by the `rug` feature.
+//!
+//! [`rug::Rational`]: https://docs.rs/rug/
+
+use std::cmp;
+
+use rug::Rational as BigRational;
+
+use super::{DynRatio, Ratio, Rational};
+
+impl From<DynRatio> for BigRational {
+    fn from(r: DynRatio) -> Self {
+        BigRational::from((r.numer(), r.denom()))
+    }
+}
+
+impl<N, D> From<Ratio<N, D>> for BigRational
+    where Ratio<N, D>: Rational,
+{
+    fn from(r: Ratio<N, D>) -> Self {
+        DynRatio::from(r).into()
+    }
+}
+
+impl PartialEq<BigRational> for DynRatio {
+    fn eq(&self, rhs: &BigRational) -> bool {
+        BigRational::from(*self) == *rhs
+    }
+}
+
+impl PartialOrd<BigRational> for DynRatio {
+    fn partial_cmp(&self, rhs: &BigRational) -> Option<cmp::Ordering> {
+        BigRational::from(*self).partial_cmp(rhs)
+    }
+}
+
+impl<N, D> PartialEq<BigRational> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+{
+    fn eq(&self, rhs: &BigRational) -> bool {
+        BigRational::from(DynRatio::from(Ratio::<N, D>::default())) == *rhs
+    }
+}
+
+impl<N, D> PartialOrd<BigRational> for Ratio<N, D>
+    where Ratio<N, D>: Rational + PartialEq<BigRational>,
+{
+    fn partial_cmp(&self, rhs: &BigRational) -> Option<cmp::Ordering> {
+        BigRational::from(DynRatio::from(Ratio::<N, D>::default())).partial_cmp(rhs)
+    }
+}
@@ -0,0 +1,194 @@
+//! Serialization of [`DynRatio`], enabled by the `serde` feature.
+//!
+//! By default, a `DynRatio` is serialized as the string `"n/d"`. Wrap it in a [`CompactRatio`] to
+//! use a binary encoding instead.
+//!
+//! [`DynRatio`]: ../struct.DynRatio.html
+//! [`CompactRatio`]: ../struct.CompactRatio.html
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{self, SeqAccess, Visitor};
+
+use super::DynRatio;
+use super::dyn_ratio::gcd;
+
+impl Serialize for DynRatio {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DynRatio {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(TextVisitor)
+    }
+}
+
+struct TextVisitor;
+
+impl<'de> Visitor<'de> for TextVisitor {
+    type Value = DynRatio;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a ratio of the form \"n/d\"")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<DynRatio, E> {
+        let (num, den) = match s.find('/') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => (s, "1"),
+        };
+
+        let num = num.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(s), &self))?;
+        let den = den.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(s), &self))?;
+        checked_new(num, den)
+    }
+}
+
+/// A wrapper around [`DynRatio`] which serializes it as a compact sequence of bytes.
+///
+/// The numerator is [zig-zag] encoded and written as an unsigned [LEB128] varint, followed by the
+/// denominator as an unsigned LEB128 varint. Small ratios such as `1/3` occupy two bytes, which
+/// makes this encoding suitable for binary formats like `postcard` or `bincode`.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate typenum_ratio;
+/// # #[cfg(feature = "serde")]
+/// # fn main() {
+/// use typenum_ratio::{CompactRatio, DynRatio};
+///
+/// let compact = CompactRatio(DynRatio::new(-1, 3));
+/// assert_eq!(compact.to_bytes(), vec![0x01, 0x03]);
+/// assert_eq!(CompactRatio::from_bytes(&[0x01, 0x03]), Some(compact));
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+///
+/// [`DynRatio`]: ./struct.DynRatio.html
+/// [zig-zag]: https://developers.google.com/protocol-buffers/docs/encoding#signed-ints
+/// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompactRatio(pub DynRatio);
+
+impl CompactRatio {
+    /// Encodes the wrapped ratio using the compact binary representation.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let num = self.0.numer();
+        let mut bytes = Vec::with_capacity(20);
+        write_varint(&mut bytes, ((num << 1) ^ (num >> 63)) as u64);
+        write_varint(&mut bytes, self.0.denom() as u64);
+        bytes
+    }
+
+    /// Decodes a ratio from the compact binary representation.
+    ///
+    /// Returns `None` if `bytes` is malformed, has trailing data or encodes a zero denominator.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut bytes = bytes.iter().cloned();
+        let num = read_varint(&mut bytes)?;
+        let den = read_varint(&mut bytes)?;
+        if bytes.next().is_some() || den == 0 || den > i64::max_value() as u64 {
+            return None;
+        }
+
+        let num = ((num >> 1) as i64) ^ -((num & 1) as i64);
+        Some(CompactRatio(DynRatio::new(num, den as i64)))
+    }
+}
+
+impl From<DynRatio> for CompactRatio {
+    fn from(r: DynRatio) -> Self {
+        CompactRatio(r)
+    }
+}
+
+impl From<CompactRatio> for DynRatio {
+    fn from(r: CompactRatio) -> Self {
+        r.0
+    }
+}
+
+impl Serialize for CompactRatio {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for CompactRatio {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(CompactVisitor)
+    }
+}
+
+struct CompactVisitor;
+
+impl<'de> Visitor<'de> for CompactVisitor {
+    type Value = CompactRatio;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a compact binary ratio")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<CompactRatio, E> {
+        CompactRatio::from_bytes(bytes)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Bytes(bytes), &self))
+    }
+
+    // Self-describing formats without a native byte string type encode bytes as a sequence.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<CompactRatio, A::Error> {
+        let mut bytes = Vec::with_capacity(20);
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+
+        self.visit_bytes(&bytes)
+    }
+}
+
+fn checked_new<E: de::Error>(num: i64, den: i64) -> Result<DynRatio, E> {
+    if den == 0 {
+        return Err(E::custom("denominator of a ratio must be non-zero"));
+    }
+
+    let (num, den) = (i128::from(num), i128::from(den));
+    let g = gcd(num, den) * den.signum();
+    let (num, den) = (num / g, den / g);
+    if num < i128::from(i64::min_value()) || num > i128::from(i64::max_value())
+        || den > i128::from(i64::max_value())
+    {
+        return Err(E::custom("ratio does not fit in an `i64`"));
+    }
+
+    Ok(DynRatio::new(num as i64, den as i64))
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        bytes.push(n as u8 | 0x80);
+        n >>= 7;
+    }
+
+    bytes.push(n as u8);
+}
+
+fn read_varint<I: Iterator<Item = u8>>(bytes: &mut I) -> Option<u64> {
+    let mut n = 0u64;
+    for shift in (0..64).step_by(7) {
+        let b = bytes.next()?;
+        if shift == 63 && b > 1 {
+            return None;
+        }
+
+        n |= u64::from(b & 0x7f) << shift;
+        if b & 0x80 == 0 {
+            return Some(n);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,147 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::*;
+
+use typenum::{
+    B1,
+    Max, Min,
+    array::{ATerm, TArr},
+    operator_aliases::*,
+    type_operators::IsLessOrEqual,
+};
+
+use super::{type_operators::*, operator_aliases::{MaxOf, MinOf}};
+
+/// A closed interval `[Lo, Hi]` whose endpoints are [`Ratio`]s known at compile time.
+///
+/// `Lo` must not be greater than `Hi`. Intervals support interval arithmetic with `+`, `-`, `*`
+/// and unary `-`: the result of an operation on two intervals is the smallest interval which
+/// contains the result of the operation on every pair of their elements. The endpoints of a
+/// result are reduced.
+///
+/// See also [`Intersection`] and [`Contains`].
+///
+/// # Example
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, RatInterval, operator_aliases::*};
+///
+/// // A 1% resistor with a nominal value of 100, in a divider with a 5% resistor.
+/// type R1 = RatInterval<Ratio<P99>, Ratio<P101>>;
+/// type R2 = RatInterval<Ratio<P95>, Ratio<P105>>;
+///
+/// assert_eq!(Sum::<R1, R2>::default(), RatInterval::<Ratio<P194>, Ratio<P206>>::default());
+/// assert_eq!(Diff::<R1, R2>::default(), RatInterval::<Ratio<N6>, Ratio<P6>>::default());
+/// assert_eq!(Prod::<R1, RatInterval<Ratio<N1>, Ratio<P1, P2>>>::default(),
+///            RatInterval::<Ratio<N101>, Ratio<P101, P2>>::default());
+/// ```
+///
+/// [`Ratio`]: ./struct.Ratio.html
+/// [`Intersection`]: ./operator_aliases/type.Intersection.html
+/// [`Contains`]: ./operator_aliases/type.Contains.html
+pub struct RatInterval<Lo, Hi>(PhantomData<(Lo, Hi)>);
+
+impl<Lo, Hi> Default for RatInterval<Lo, Hi> {
+    fn default() -> Self {
+        RatInterval(PhantomData)
+    }
+}
+
+impl<Lo1, Hi1, Lo2, Hi2> PartialEq<RatInterval<Lo2, Hi2>> for RatInterval<Lo1, Hi1>
+    where Lo1: PartialEq<Lo2> + Default,
+          Hi1: PartialEq<Hi2> + Default,
+          Lo2: Default,
+          Hi2: Default,
+{
+    fn eq(&self, _: &RatInterval<Lo2, Hi2>) -> bool {
+        Lo1::default() == Lo2::default() && Hi1::default() == Hi2::default()
+    }
+}
+
+impl<Lo, Hi> fmt::Debug for RatInterval<Lo, Hi>
+    where Lo: fmt::Debug + Default,
+          Hi: fmt::Debug + Default,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{:?}, {:?}]", Lo::default(), Hi::default())
+    }
+}
+
+/// [Lo1, Hi1] + [Lo2, Hi2] = [Lo1 + Lo2, Hi1 + Hi2]
+impl<Lo1, Hi1, Lo2, Hi2> Add<RatInterval<Lo2, Hi2>> for RatInterval<Lo1, Hi1>
+    where Lo1: Add<Lo2>,
+          Hi1: Add<Hi2>,
+{
+    type Output = RatInterval<Sum<Lo1, Lo2>, Sum<Hi1, Hi2>>;
+
+    fn add(self, _: RatInterval<Lo2, Hi2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// [Lo1, Hi1] - [Lo2, Hi2] = [Lo1 - Hi2, Hi1 - Lo2]
+impl<Lo1, Hi1, Lo2, Hi2> Sub<RatInterval<Lo2, Hi2>> for RatInterval<Lo1, Hi1>
+    where Lo1: Sub<Hi2>,
+          Hi1: Sub<Lo2>,
+{
+    type Output = RatInterval<Diff<Lo1, Hi2>, Diff<Hi1, Lo2>>;
+
+    fn sub(self, _: RatInterval<Lo2, Hi2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+type Products<Lo1, Hi1, Lo2, Hi2> = TArr<
+    Prod<Lo1, Lo2>,
+    TArr<Prod<Lo1, Hi2>, TArr<Prod<Hi1, Lo2>, TArr<Prod<Hi1, Hi2>, ATerm>>>,
+>;
+
+/// The least and greatest of the products of the endpoints.
+impl<Lo1, Hi1, Lo2, Hi2> Mul<RatInterval<Lo2, Hi2>> for RatInterval<Lo1, Hi1>
+    where Lo1: Mul<Lo2> + Mul<Hi2>,
+          Hi1: Mul<Lo2> + Mul<Hi2>,
+          Products<Lo1, Hi1, Lo2, Hi2>: MinimumList + MaximumList,
+{
+    type Output = RatInterval<
+        MinOf<Products<Lo1, Hi1, Lo2, Hi2>>,
+        MaxOf<Products<Lo1, Hi1, Lo2, Hi2>>,
+    >;
+
+    fn mul(self, _: RatInterval<Lo2, Hi2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// -[Lo, Hi] = [-Hi, -Lo]
+impl<Lo, Hi> Neg for RatInterval<Lo, Hi>
+    where Lo: Neg,
+          Hi: Neg,
+{
+    type Output = RatInterval<Negate<Hi>, Negate<Lo>>;
+
+    fn neg(self) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// [max(Lo1, Lo2), min(Hi1, Hi2)], which must not be empty.
+impl<Lo1, Hi1, Lo2, Hi2> IntervalIntersection<RatInterval<Lo2, Hi2>> for RatInterval<Lo1, Hi1>
+    where Lo1: Max<Lo2>,
+          Hi1: Min<Hi2>,
+          Maximum<Lo1, Lo2>: IsLessOrEqual<Minimum<Hi1, Hi2>, Output = B1>,
+{
+    type Output = RatInterval<Maximum<Lo1, Lo2>, Minimum<Hi1, Hi2>>;
+}
+
+/// Lo <= R && R <= Hi
+impl<Lo, Hi, R> IntervalContains<R> for RatInterval<Lo, Hi>
+    where Lo: IsLessOrEqual<R>,
+          R: IsLessOrEqual<Hi>,
+          LeEq<Lo, R>: BitAnd<LeEq<R, Hi>>,
+{
+    type Output = And<LeEq<Lo, R>, LeEq<R, Hi>>;
+}
@@ -1,17 +1,97 @@
 //! Compile-time rational arithmetic built on top of [`typenum`].
 //!
+//! # Recursion limits
+//!
+//! Every operation on a [`Ratio`] reduces its result using a compile-time GCD, and large numerators,
+//! denominators or exponents can exhaust the default recursion limit of the trait solver. This
+//! manifests as an "overflow evaluating the requirement" error deep inside `typenum`, whose
+//! "required for" notes lead back to the offending `Ratio` operation. If an operation is known to
+//! be well-formed, raise the limit at the root of the crate which uses it:
+//!
+//! ```rust
+//! #![recursion_limit = "256"]
+//! # fn main() {}
+//! ```
+//!
 //! [`typenum`]: https://docs.rs/typenum/
+//! [`Ratio`]: ./struct.Ratio.html
 
 extern crate typenum;
 
+#[cfg(feature = "approx")]
+extern crate approx;
+#[cfg(feature = "malachite-q")]
+extern crate malachite_q;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "rug")]
+extern crate rug;
+#[cfg(feature = "serde")]
+extern crate serde;
+
+/// Fails to compile any instantiation of the enclosing function for which the associated constant
+/// `ASSERT` of `$check` does not evaluate.
+macro_rules! const_assert {
+    ($check:ty) => {
+        #[allow(clippy::let_unit_value)]
+        let () = <$check>::ASSERT;
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+pub mod color;
 pub mod consts;
 pub mod operator_aliases;
+pub mod percent;
+pub mod pwm;
+pub mod rate_limit;
+pub mod rounding;
+pub mod type_operators;
+pub mod video;
+mod cfrac;
+mod digits;
+mod dyn_ratio;
+mod exact;
+mod float;
+mod interval;
+mod list;
+mod log;
+mod matrix;
 mod ratio;
+mod raw;
+mod root;
+mod round;
+mod scalar;
+mod tree;
+mod uratio;
+
+#[cfg(feature = "approx")]
+mod impl_approx;
+#[cfg(feature = "malachite-q")]
+mod impl_malachite;
+#[cfg(feature = "rug")]
+mod impl_rug;
+#[cfg(feature = "serde")]
+mod impl_serde;
 
+#[doc(hidden)]
+pub mod private;
+
+pub use dyn_ratio::{DisplayPercent, DynRatio, FareySequence, RatioRange, RatioRangeIter};
+pub use exact::{DivExact, Remainder};
+pub use interval::RatInterval;
+pub use list::{RatioList, to_fixed_point, to_pairs};
+pub use matrix::{Mobius, RatMat2};
 pub use ratio::Ratio;
+pub use raw::RawRatio;
+pub use scalar::Scalar;
+pub use uratio::URatio;
+#[cfg(feature = "serde")]
+pub use impl_serde::CompactRatio;
 pub use operator_aliases::*;
 
-use typenum::{Integer, NonZero};
+use typenum::{Integer, NonZero, Unsigned};
 
 /// A type representing a rational number whose value is known at compile time.
 ///
@@ -22,7 +102,14 @@ use typenum::{Integer, NonZero};
 /// * `Self::Num / Self::Den` is a [reduced fraction][reduced]. In other words, the greatest common
 ///   divisor of `Self::Num` and `Self::Den` is `1`.
 ///
+/// Besides [`Ratio`], every `typenum::Integer` and `typenum::Unsigned` implements `Rational` as
+/// itself over one.
+///
 /// [reduced]: http://mathworld.wolfram.com/ReducedFraction.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a valid rational number",
+    note = "the denominator of a `Ratio` must be a non-zero `typenum::Integer`",
+)]
 pub trait Rational {
     /// The numerator of the rational number.
     type Num: Integer;
@@ -33,6 +120,77 @@ pub trait Rational {
     type Den: Integer + NonZero;
 }
 
+/// A type representing a non-negative rational number whose value is known at compile time.
+///
+/// This is the unsigned counterpart of [`Rational`], implemented by [`URatio`]. Implementors must
+/// ensure that `Self::Num / Self::Den` is a [reduced fraction][reduced].
+///
+/// [`Rational`]: ./trait.Rational.html
+/// [`URatio`]: ./struct.URatio.html
+/// [reduced]: http://mathworld.wolfram.com/ReducedFraction.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a valid unsigned rational number",
+    note = "the denominator of a `URatio` must be a non-zero `typenum::Unsigned`",
+)]
+pub trait UnsignedRational {
+    /// The numerator of the rational number.
+    type Num: Unsigned;
+
+    /// The denominator of the rational number.
+    type Den: Unsigned + NonZero;
+}
+
+/// A [`Rational`] which can be converted to a [`URatio`].
+///
+/// This is implemented for every non-negative [`Rational`]. See [`AsURatio`].
+///
+/// [`Rational`]: ./trait.Rational.html
+/// [`URatio`]: ./struct.URatio.html
+/// [`AsURatio`]: ./operator_aliases/type.AsURatio.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be converted to a `URatio`",
+    note = "only non-negative `Rational`s can be converted",
+)]
+pub trait ToURatio {
+    /// The unsigned ratio equal to `Self`.
+    type Output: UnsignedRational;
+}
+
+/// A type which can be promoted to a [`Rational`].
+///
+/// This is implemented for every [`Rational`], including [`typenum::Integer`]s,
+/// [`typenum::Unsigned`]s and user-defined types. The result is always the reduced [`Ratio`],
+/// allowing generic code to accept any "ratio-like" type and operate on a single canonical
+/// representation.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::AsRatio};
+///
+/// assert_eq!(AsRatio::<P3>::default(), Ratio::<P3>::default());
+/// assert_eq!(AsRatio::<U3>::default(), Ratio::<P3>::default());
+/// assert_eq!(AsRatio::<Ratio<P2, P4>>::default(), Ratio::<P1, P2>::default());
+/// ```
+///
+/// [`Rational`]: ./trait.Rational.html
+/// [`Ratio`]: ./struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+/// [`typenum::Unsigned`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be converted to a `Ratio`",
+    note = "only `Rational`s, including `typenum::Integer`s and `typenum::Unsigned`s, can be \
+            promoted",
+)]
+pub trait ToRatio {
+    /// The rational number equal to `Self`.
+    type Output: Rational;
+}
+
 /// Creates a [`Ratio`] from two type-level integers.
 ///
 /// [`Ratio`]: ./struct.Ratio.html
@@ -55,10 +213,161 @@ macro_rules! rat {
     }
 }
 
+/// Asserts at compile time that a [`Rational`] is within a rational tolerance of a float literal.
+///
+/// The check is performed during constant evaluation, so a mistyped constant results in a
+/// compilation error rather than a test failure.
+///
+/// [`Rational`]: ./trait.Rational.html
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate typenum_ratio;
+/// extern crate typenum;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::Ratio;
+///
+/// type MyPi = Ratio<P355, P113>;
+///
+/// assert_rat_approx!(MyPi, 3.14159265, tol = Ratio<P1, P1000000>);
+/// # fn main() {}
+/// ```
+///
+/// ```rust,compile_fail
+/// # #[macro_use] extern crate typenum_ratio;
+/// extern crate typenum;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::Ratio;
+///
+/// type MyPi = Ratio<P22, P7>;
+///
+/// assert_rat_approx!(MyPi, 3.14159265, tol = Ratio<P1, P1000000>);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_rat_approx {
+    ($r:ty, $x:expr, tol = $tol:ty) => {
+        const _: () = {
+            use $crate::private::Integer;
+
+            let value = <<$r as $crate::Rational>::Num as Integer>::I64 as f64
+                / <<$r as $crate::Rational>::Den as Integer>::I64 as f64;
+            let tol = <<$tol as $crate::Rational>::Num as Integer>::I64 as f64
+                / <<$tol as $crate::Rational>::Den as Integer>::I64 as f64;
+
+            let diff = value - $x;
+            let diff = if diff < 0.0 { -diff } else { diff };
+            assert!(diff <= tol, "ratio is not within the given tolerance");
+        };
+    }
+}
+
+/// Implements the arithmetic operators for a type which implements [`Rational`].
+///
+/// Each operator behaves as if `Self` were the reduced [`Ratio`] equal to it, so the output is
+/// always a `Ratio`. This implements `Add`, `Sub`, `Mul`, `Div` and `Rem` with any right-hand
+/// side supported by `Ratio`, as well as `Neg` and `typenum::Pow`.
+///
+/// [`Rational`]: ./trait.Rational.html
+/// [`Ratio`]: ./struct.Ratio.html
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use] extern crate typenum_ratio;
+/// extern crate typenum;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, Rational};
+///
+/// struct BasisPoint;
+///
+/// impl Rational for BasisPoint {
+///     type Num = P1;
+///     type Den = P10000;
+/// }
+///
+/// impl_rational_ops!(BasisPoint);
+///
+/// assert_eq!(BasisPoint * P25::new(), rat!(P1/P400));
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! impl_rational_ops {
+    ($t:ty) => {
+        impl_rational_ops!(@binary $t, Add, add);
+        impl_rational_ops!(@binary $t, Sub, sub);
+        impl_rational_ops!(@binary $t, Mul, mul);
+        impl_rational_ops!(@binary $t, Div, div);
+        impl_rational_ops!(@binary $t, Rem, rem);
+
+        impl ::std::ops::Neg for $t
+            where $crate::Ratio<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>:
+                ::std::ops::Neg,
+        {
+            type Output = <$crate::Ratio<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>
+                as ::std::ops::Neg>::Output;
+
+            fn neg(self) -> Self::Output {
+                -$crate::Ratio::<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>::default()
+            }
+        }
+
+        impl<E> $crate::private::Pow<E> for $t
+            where $crate::Ratio<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>:
+                $crate::private::Pow<E>,
+        {
+            type Output = <$crate::Ratio<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>
+                as $crate::private::Pow<E>>::Output;
+
+            fn powi(self, exp: E) -> Self::Output {
+                $crate::private::Pow::powi(
+                    $crate::Ratio::<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>::default(),
+                    exp,
+                )
+            }
+        }
+    };
+
+    (@binary $t:ty, $op:ident, $method:ident) => {
+        impl<R> ::std::ops::$op<R> for $t
+            where $crate::Ratio<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>:
+                ::std::ops::$op<R>,
+        {
+            type Output = <$crate::Ratio<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>
+                as ::std::ops::$op<R>>::Output;
+
+            fn $method(self, rhs: R) -> Self::Output {
+                ::std::ops::$op::$method(
+                    $crate::Ratio::<<$t as $crate::Rational>::Num, <$t as $crate::Rational>::Den>::default(),
+                    rhs,
+                )
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use typenum::{consts::*, operator_aliases::*};
+    use typenum::{B0, B1, Bit, consts::*, operator_aliases::*, type_operators::{Pow, Same}};
+    use typenum::array::{ATerm, TArr};
+
+    /// Fails to compile unless `A` and `B` are the same type.
+    fn same<A: Same<B>, B>() {}
+
+    /// A user-defined `Rational` equal to twelve, with the arithmetic operators of a `Ratio`.
+    struct Dozen;
+
+    impl Rational for Dozen {
+        type Num = P12;
+        type Den = P1;
+    }
+
+    impl_rational_ops!(Dozen);
 
     #[test]
     fn reduce() {
@@ -67,6 +376,152 @@ mod tests {
 
         assert_eq!(rat!(N1/N1), rat!(P1/P1));
         assert_eq!(rat!(N1/P1), rat!(P1/N1));
+
+        same::<Reduce<P3, P9>, Ratio<P1, P3>>();
+        same::<Reduce<N4, N6>, Ratio<P2, P3>>();
+        same::<Reduce<P4, N6>, Ratio<N2, P3>>();
+        same::<Reduce<Z0, N6>, Ratio<Z0, P1>>();
+        same::<Reduce<P5, P1>, Ratio<P5, P1>>();
+        same::<Reduce<N6, P4>, Ratio<N3, P2>>();
+    }
+
+    #[test]
+    fn rational_operators() {
+        // Neither operand needs to be a `Ratio`.
+        same::<RatSum<Dozen, Dozen>, Ratio<P24, P1>>();
+        same::<RatDiff<U1, Dozen>, Ratio<N11, P1>>();
+        same::<RatProd<N2, Dozen>, Ratio<N24, P1>>();
+        same::<RatQuot<Dozen, U8>, Ratio<P3, P2>>();
+        same::<RatSum<Ratio<P2, P4>, Ratio<P2, P4>>, Ratio<P1, P1>>();
+    }
+
+    #[test]
+    fn rational_rhs() {
+        same::<Sum<Ratio<P1, P2>, Dozen>, Ratio<P25, P2>>();
+        same::<Diff<Ratio<P1, P2>, Dozen>, Ratio<N23, P2>>();
+        same::<Prod<Ratio<P1, P8>, Dozen>, Ratio<P3, P2>>();
+        same::<Quot<Ratio<P3>, Dozen>, Ratio<P1, P4>>();
+        same::<Mod<Ratio<P25>, Dozen>, Ratio<P1, P1>>();
+
+        same::<Sum<Ratio<P2, P4>, P1>, Ratio<P3, P2>>();
+        same::<Sum<Ratio<P1, P2>, U3>, Ratio<P7, P2>>();
+        same::<Diff<Ratio<P1, P2>, U1>, Ratio<N1, P2>>();
+        same::<Prod<Ratio<P3, P4>, U1024>, Ratio<P768>>();
+        same::<Quot<Ratio<P3, P4>, U6>, Ratio<P1, P8>>();
+        same::<Mod<Ratio<P7, P2>, U2>, Ratio<P3, P2>>();
+        same::<Prod<Ratio<P3, P4>, U0>, Ratio<Z0>>();
+        same::<Diff<Ratio<P2, P4>, N1>, Ratio<P3, P2>>();
+        same::<Prod<Ratio<P1, P6>, P3>, Ratio<P1, P2>>();
+        same::<Quot<Ratio<P3, P2>, N3>, Ratio<N1, P2>>();
+        same::<Mod<Ratio<P7, P2>, P2>, Ratio<P3, P2>>();
+    }
+
+    #[test]
+    fn reduce_fast_paths() {
+        same::<Reduce<Z0, P1000>, Ratio<Z0, P1>>();
+        same::<Reduce<P1000, P1>, Ratio<P1000, P1>>();
+        same::<Reduce<N1, P1000>, Ratio<N1, P1000>>();
+        same::<Reduce<P96, P64>, Ratio<P3, P2>>();
+        same::<Reduce<P1000, N10>, Ratio<N100, P1>>();
+        same::<Reduce<P10, P1000>, Ratio<P1, P100>>();
+        same::<Reduce<P12, P9>, Ratio<P4, P3>>();
+        same::<Reduce<P15, P10>, Ratio<P3, P2>>();
+        same::<Reduce<P1024, P512>, Ratio<P2, P1>>();
+    }
+
+    #[test]
+    fn whole_ratio_to_int() {
+        assert_eq!(Ratio::<P6, P2>::to_i32(), 3);
+        assert_eq!(Ratio::<N6, P3>::to_i64(), -2);
+        assert_eq!(Ratio::<Z0, N5>::to_isize(), 0);
+        assert_eq!(Ratio::<P1000, P4>::I16, 250);
+        assert_eq!(Prod::<Ratio<P3, P4>, Ratio<P8>>::I8, 6);
+
+        fn abs<I: Integer>() -> i64 {
+            I::to_i64().abs()
+        }
+
+        assert_eq!(abs::<Ratio<N12, P3>>(), 4);
+    }
+
+    #[test]
+    fn to_integer() {
+        same::<ToInteger<Ratio<P6, P2>>, P3>();
+        same::<ToInteger<Ratio<N6, P3>>, N2>();
+        same::<ToInteger<Ratio<Z0, N5>>, Z0>();
+        same::<ToInteger<N7>, N7>();
+        same::<ToInteger<URatio<U8, U4>>, P2>();
+        same::<ToInteger<Sum<Ratio<P1, P2>, Ratio<P3, P2>>>, P2>();
+    }
+
+    #[test]
+    fn scalar() {
+        same::<Sum<Scalar<P2>, Ratio<P1, P2>>, Ratio<P5, P2>>();
+        same::<Diff<Scalar<P2>, Ratio<P1, P2>>, Ratio<P3, P2>>();
+        same::<Prod<Scalar<N2>, Ratio<P1, P4>>, Ratio<N1, P2>>();
+        same::<Quot<Scalar<P2>, Ratio<P1, P3>>, Ratio<P6>>();
+        same::<Mod<Scalar<P7>, Ratio<P3, P2>>, Ratio<P1>>();
+        same::<Quot<Scalar<U1>, P4>, Ratio<P1, P4>>();
+        same::<Sum<Ratio<P1, P2>, Scalar<P1>>, Ratio<P3, P2>>();
+
+        assert_eq!(Scalar::<P2>::new() / rat!(P1/P3), Ratio::<P6>::default());
+        assert_eq!(format!("{:?}", Scalar::<U4>::new()), "Scalar(4/1)");
+    }
+
+    #[test]
+    fn uratio() {
+        same::<<URatio<U6, U4> as UnsignedRational>::Num, U3>();
+        same::<<URatio<U6, U4> as UnsignedRational>::Den, U2>();
+        same::<<URatio<U0, U4> as UnsignedRational>::Den, U1>();
+
+        same::<Sum<URatio<U1, U2>, URatio<U1, U3>>, URatio<U5, U6>>();
+        same::<Diff<URatio<U1, U2>, URatio<U1, U3>>, URatio<U1, U6>>();
+        same::<Diff<URatio<U1, U2>, URatio<U2, U4>>, URatio<U0, U1>>();
+        same::<Prod<URatio<U2, U3>, URatio<U3, U4>>, URatio<U1, U2>>();
+        same::<Quot<URatio<U2, U3>, URatio<U4, U9>>, URatio<U3, U2>>();
+
+        same::<AsRatio<URatio<U6, U4>>, Ratio<P3, P2>>();
+        same::<AsRatio<URatio<U0, U4>>, Ratio<Z0, P1>>();
+        same::<AsURatio<Ratio<P6, P4>>, URatio<U3, U2>>();
+        same::<AsURatio<Ratio<N6, N4>>, URatio<U3, U2>>();
+        same::<AsURatio<Ratio<Z0, N4>>, URatio<U0, U1>>();
+        same::<AsURatio<P7>, URatio<U7, U1>>();
+        same::<Sum<Ratio<N1>, URatio<U3, U2>>, Ratio<P1, P2>>();
+
+        assert_eq!(URatio::<U2, U4>::default(), URatio::<U1, U2>::default());
+        assert_eq!(format!("{:?}", URatio::<U6, U4>::default()), "3/2");
+    }
+
+    #[test]
+    fn raw_ratio() {
+        same::<Sum<RawRatio<P1, P2>, RawRatio<P1, P2>>, RawRatio<P4, P4>>();
+        same::<Diff<RawRatio<P1, P2>, RawRatio<P1, P3>>, RawRatio<P1, P6>>();
+        same::<Prod<RawRatio<P2, P3>, RawRatio<P3, P4>>, RawRatio<P6, P12>>();
+        same::<Quot<RawRatio<P2, P3>, RawRatio<N2, P3>>, RawRatio<P6, N6>>();
+        same::<Negate<RawRatio<P2, P4>>, RawRatio<N2, P4>>();
+
+        same::<AsRatio<RawRatio<P4, P4>>, Ratio<P1>>();
+        same::<AsRatio<RawRatio<P6, N6>>, Ratio<N1>>();
+        same::<AsRawRatio<Ratio<P2, P4>>, RawRatio<P1, P2>>();
+        same::<Sum<Ratio<P1, P2>, RawRatio<P2, P4>>, Ratio<P1>>();
+
+        assert_eq!(RawRatio::<P2, P4>::default(), RawRatio::<N1, N2>::default());
+        assert_eq!(format!("{:?}", RawRatio::<P6, N4>::default()), "-3/2");
+    }
+
+    #[test]
+    fn impl_rational_ops() {
+        same::<Sum<Dozen, Ratio<P1, P3>>, Ratio<P37, P3>>();
+        same::<Diff<Dozen, P13>, Ratio<N1, P1>>();
+        same::<Prod<Dozen, Dozen>, Ratio<P144, P1>>();
+        same::<Quot<Dozen, Ratio<P3, P2>>, Ratio<P8, P1>>();
+        same::<Mod<Dozen, P5>, Ratio<P2, P1>>();
+        same::<Negate<Dozen>, Ratio<N12, P1>>();
+        same::<Exp<Dozen, N1>, Ratio<P1, P12>>();
+
+        assert_eq!(Dozen * rat!(P1/P3), rat!(P4/P1));
+        assert_eq!(-Dozen, rat!(N12/P1));
+        assert_eq!(Dozen.powi(U2::new()), rat!(P144/P1));
     }
 
     #[test]
@@ -77,10 +532,48 @@ mod tests {
         assert_eq!(-rat!(N1/N2), rat!(N1/P2));
     }
 
+    #[test]
+    fn lcm() {
+        same::<Lcmf<Ratio<P3, P4>, Ratio<P5, P6>>, Ratio<P15, P2>>();
+        same::<Lcmf<Ratio<P1, P2>, Ratio<P1, P3>>, Ratio<P1, P1>>();
+        same::<Lcmf<Ratio<N2, P3>, Ratio<P4, P9>>, Ratio<P4, P3>>();
+        same::<Lcmf<Ratio<P3, P2>, P2>, Ratio<P6, P1>>();
+        same::<Lcmf<Ratio<P3, P2>, U4>, Ratio<P12, P1>>();
+        same::<Lcmf<P2, Ratio<P3, P2>>, Ratio<P6, P1>>();
+        same::<Lcmf<U4, Ratio<P1, P3>>, Ratio<P4, P1>>();
+        same::<Lcmf<Ratio<P1, P3>, URatio<U1, U2>>, Ratio<P1, P1>>();
+    }
+
+    #[test]
+    fn lcd() {
+        same::<Lcd<Ratio<P1, P4>, Ratio<P1, P6>>, P12>();
+        same::<Lcd<Ratio<P1, P6>, Ratio<P1, P4>>, P12>();
+        same::<Lcd<P2, Ratio<P1, P3>>, P3>();
+        same::<Lcd<Ratio<P3, P6>, U5>, P2>();
+        same::<NumOverLcd<Ratio<P1, P4>, Ratio<P1, P6>>, P3>();
+        same::<NumOverLcd<Ratio<P1, P6>, Ratio<P1, P4>>, P2>();
+        same::<NumOverLcd<Ratio<N2, P8>, Ratio<P5, P6>>, N3>();
+        same::<NumOverLcd<P2, Ratio<P1, P3>>, P6>();
+    }
+
     #[test]
     fn cmp() {
+        use typenum::{Equal, Greater, Less, type_operators::{Max, Min}};
+
         assert!(rat!(P2/P3) > rat!(P3/P5));
         assert!(rat!(N1/N2) > rat!(P1/N2));
+
+        assert!(rat!(P1/P3) < rat!(P4/P6));
+        assert!(rat!(N5/P7) < rat!(P3/P7));
+        assert!(rat!(P2/P4) >= rat!(P1/P2));
+
+        same::<Compare<Ratio<P1, P3>, Ratio<P1, P2>>, Less>();
+        same::<Compare<Ratio<P2, P4>, Ratio<P1, P2>>, Equal>();
+        same::<Compare<Ratio<N1, P3>, Ratio<N2, P3>>, Greater>();
+        same::<Minimum<Ratio<P1, P3>, Ratio<P1, P2>>, Ratio<P1, P3>>();
+        same::<Maximum<Ratio<P1, P3>, Ratio<P1, P2>>, Ratio<P1, P2>>();
+        assert_eq!(Max::max(rat!(N7/P2), rat!(N4/P1)), rat!(N7/P2));
+        assert_eq!(Min::min(rat!(N7/P2), rat!(N4/P1)), rat!(N4/P1));
     }
 
     #[test]
@@ -127,5 +620,1017 @@ mod tests {
         assert_eq!(Gcf::<Ratio<P9, P8>, Ratio<P3, P16>>::default(), rat!(P3/P16));
         assert_eq!(Gcf::<Ratio<P3, P7>, Ratio<P12, P22>>::default(), rat!(P3/P77));
         assert_eq!(Gcf::<Ratio<P13, P6>, Ratio<P3, P4>>::default(), rat!(P1/P12));
+
+        same::<Gcf<Ratio<P3, P2>, P6>, Ratio<P3, P2>>();
+        same::<Gcf<Ratio<P3, P2>, U9>, Ratio<P3, P2>>();
+        same::<Gcf<U9, Ratio<P3, P2>>, Ratio<P3, P2>>();
+        same::<Gcf<P4, Ratio<P2, P3>>, Ratio<P2, P3>>();
+        same::<Gcf<Ratio<P1, P4>, URatio<U1, U6>>, Ratio<P1, P12>>();
+    }
+
+    #[test]
+    fn clamp() {
+        type Lo = Ratio<N1, P2>;
+        type Hi = Ratio<P2, P3>;
+
+        same::<Clamp<Ratio<N1>, Lo, Hi>, Lo>();
+        same::<Clamp<Ratio<Z0>, Lo, Hi>, Ratio<Z0>>();
+        same::<Clamp<Ratio<P1>, Lo, Hi>, Hi>();
+        same::<Clamp<Ratio<P4, P6>, Lo, Hi>, Ratio<P4, P6>>();
+        same::<Clamp<Ratio<P1>, Hi, Hi>, Hi>();
+    }
+
+    #[test]
+    fn comparison_operators() {
+        assert!(Le::<Ratio<P1, P3>, Ratio<P1, P2>>::to_bool());
+        assert!(!Le::<Ratio<P1, P2>, Ratio<P2, P4>>::to_bool());
+        assert!(LeEq::<Ratio<P1, P2>, Ratio<P2, P4>>::to_bool());
+        assert!(Gr::<Ratio<N1, P3>, Ratio<N1, P2>>::to_bool());
+        assert!(GrEq::<Ratio<P3>, Ratio<P6, P2>>::to_bool());
+        assert!(Eq::<Ratio<P3, N9>, Ratio<N1, P3>>::to_bool());
+        assert!(NotEq::<Ratio<P1, P3>, Ratio<P1, P2>>::to_bool());
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(Exp::<Ratio<P2, P3>, P2>::default(), rat!(P4/P9));
+        assert_eq!(Exp::<Ratio<N2, P3>, P3>::default(), rat!(N8/P27));
+        assert_eq!(Exp::<Ratio<P2, P3>, N2>::default(), rat!(P9/P4));
+        assert_eq!(Exp::<Ratio<N1, P2>, N3>::default(), rat!(N8/P1));
+        assert_eq!(Exp::<Ratio<P5, P7>, Z0>::default(), rat!(P1/P1));
+
+        assert_eq!(Exp::<Ratio<P2, P3>, U2>::default(), rat!(P4/P9));
+        assert_eq!(Exp::<Ratio<P2, P3>, U0>::default(), rat!(P1/P1));
+        assert_eq!(rat!(P1/P2).powi(U3::new()), rat!(P1/P8));
+
+        // Exponents taken from the length of a type-level array are `Unsigned`.
+        type Three = Length<TArr<P1, TArr<P2, TArr<P3, ATerm>>>>;
+        same::<Exp<Ratio<N2, P3>, Three>, Exp<Ratio<N2, P3>, P3>>();
+        same::<Exp<Ratio<P1, P2>, U10>, Ratio<P1, P1024>>();
+    }
+
+    #[test]
+    fn pow_ratio() {
+        same::<PowRatio<Ratio<P4, P9>, Ratio<P1, P2>>, Ratio<P2, P3>>();
+        same::<PowRatio<Ratio<P8>, Ratio<P2, P3>>, Ratio<P4, P1>>();
+        same::<PowRatio<Ratio<N1, P27>, Ratio<P1, P3>>, Ratio<N1, P3>>();
+        same::<PowRatio<Ratio<P4, P9>, Ratio<N3, P2>>, Ratio<P27, P8>>();
+        same::<PowRatio<Ratio<P5, P7>, Ratio<P2>>, Ratio<P25, P49>>();
+        same::<PowRatio<Ratio<Z0>, Ratio<P1, P2>>, Ratio<Z0, P1>>();
+    }
+
+    #[test]
+    fn same_ratio() {
+        use type_operators::SameRatio;
+
+        fn same_ratio<A: SameRatio<B>, B>() {}
+
+        same_ratio::<Ratio<P1, P2>, Ratio<P2, P4>>();
+        same_ratio::<Ratio<P3, N6>, Ratio<N1, P2>>();
+        same_ratio::<Ratio<Z0, P5>, Ratio<Z0, N3>>();
+        same_ratio::<Ratio<P7>, Ratio<P7>>();
+        same::<<Ratio<P6, P9> as SameRatio<Ratio<P2, P3>>>::Output, Ratio<P2, P3>>();
+    }
+
+    #[test]
+    fn sign() {
+        same::<Signum<Ratio<P3, P4>>, P1>();
+        same::<Signum<Ratio<N3, N4>>, P1>();
+        same::<Signum<Ratio<P3, N4>>, N1>();
+        same::<Signum<Ratio<N3, P4>>, N1>();
+        same::<Signum<Ratio<Z0, N4>>, Z0>();
+
+        same::<IsPositive<Ratio<P1, P1000>>, B1>();
+        same::<IsPositive<Ratio<Z0>>, B0>();
+        same::<IsPositive<Ratio<N1>>, B0>();
+        same::<IsNegative<Ratio<P1, N1000>>, B1>();
+        same::<IsNegative<Ratio<Z0>>, B0>();
+        same::<IsNegative<Ratio<P1>>, B0>();
+        same::<IsZero<Ratio<Z0, P3>>, B1>();
+        same::<IsZero<Ratio<P1, P3>>, B0>();
+        same::<IsZero<Ratio<N1, P3>>, B0>();
+    }
+
+    #[test]
+    fn is_integer() {
+        same::<IsInteger<Ratio<P4>>, B1>();
+        same::<IsInteger<Ratio<N6, P3>>, B1>();
+        same::<IsInteger<Ratio<P6, N3>>, B1>();
+        same::<IsInteger<Ratio<Z0, P7>>, B1>();
+        same::<IsInteger<Ratio<P3, P6>>, B0>();
+        same::<IsInteger<Ratio<N7, P2>>, B0>();
+    }
+
+    #[test]
+    fn divides() {
+        same::<Divides<Ratio<P3>, Ratio<P12>>, B1>();
+        same::<Divides<Ratio<N3>, Ratio<P12>>, B1>();
+        same::<Divides<Ratio<P1, P3>, Ratio<P2>>, B1>();
+        same::<Divides<Ratio<P2, P4>, Ratio<P7, P2>>, B1>();
+        same::<Divides<Ratio<P5>, Ratio<Z0>>, B1>();
+        same::<Divides<Ratio<P5>, Ratio<P12>>, B0>();
+        same::<Divides<Ratio<P3, P2>, Ratio<P2>>, B0>();
+        same::<Divides<Ratio<P2>, Ratio<P1, P2>>, B0>();
+    }
+
+    #[test]
+    fn proper_and_unit_fractions() {
+        same::<IsProperFraction<Ratio<P1, P2>>, B1>();
+        same::<IsProperFraction<Ratio<N99, P100>>, B1>();
+        same::<IsProperFraction<Ratio<Z0>>, B1>();
+        same::<IsProperFraction<Ratio<P1>>, B0>();
+        same::<IsProperFraction<Ratio<P4, N4>>, B0>();
+        same::<IsProperFraction<Ratio<P5, P3>>, B0>();
+
+        same::<IsUnitFraction<Ratio<P1, P9>>, B1>();
+        same::<IsUnitFraction<Ratio<P4, P8>>, B1>();
+        same::<IsUnitFraction<Ratio<P2, P2>>, B1>();
+        same::<IsUnitFraction<Ratio<P1, N9>>, B0>();
+        same::<IsUnitFraction<Ratio<P2, P9>>, B0>();
+        same::<IsUnitFraction<Ratio<Z0>>, B0>();
+    }
+
+    #[test]
+    fn is_dyadic() {
+        same::<IsDyadic<Ratio<P5>>, B1>();
+        same::<IsDyadic<Ratio<Z0>>, B1>();
+        same::<IsDyadic<Ratio<N7, P16>>, B1>();
+        same::<IsDyadic<Ratio<P3, P6>>, B1>();
+        same::<IsDyadic<Ratio<P1, P1024>>, B1>();
+        same::<IsDyadic<Ratio<P1, P3>>, B0>();
+        same::<IsDyadic<Ratio<P1, P12>>, B0>();
+        same::<IsDyadic<Ratio<P9, N10>>, B0>();
+    }
+
+    #[test]
+    fn is_terminating_decimal() {
+        same::<IsTerminatingDecimal<Ratio<P3>>, B1>();
+        same::<IsTerminatingDecimal<Ratio<Z0>>, B1>();
+        same::<IsTerminatingDecimal<Ratio<P1, P8>>, B1>();
+        same::<IsTerminatingDecimal<Ratio<N1, P125>>, B1>();
+        same::<IsTerminatingDecimal<Ratio<P7, P1000>>, B1>();
+        same::<IsTerminatingDecimal<Ratio<P3, P30>>, B1>();
+        same::<IsTerminatingDecimal<Ratio<P1, P3>>, B0>();
+        same::<IsTerminatingDecimal<Ratio<P1, P15>>, B0>();
+        same::<IsTerminatingDecimal<Ratio<P7, N14>>, B1>();
+        same::<IsTerminatingDecimal<Ratio<P1, P14>>, B0>();
+    }
+
+    #[test]
+    fn floor_log() {
+        same::<FloorLog2<Ratio<P1>>, Z0>();
+        same::<FloorLog2<Ratio<P7>>, P2>();
+        same::<FloorLog2<Ratio<P8>>, P3>();
+        same::<FloorLog2<Ratio<P1, P2>>, N1>();
+        same::<FloorLog2<Ratio<P3, P8>>, N2>();
+
+        same::<FloorLog10<Ratio<P1>>, Z0>();
+        same::<FloorLog10<Ratio<P999>>, P2>();
+        same::<FloorLog10<Ratio<P1000>>, P3>();
+        same::<FloorLog10<Ratio<P1, P10>>, N1>();
+        same::<FloorLog10<Ratio<P1, P11>>, N2>();
+    }
+
+    #[test]
+    fn power_of_ten() {
+        same::<IsPowerOfTen<Ratio<P1>>, B1>();
+        same::<IsPowerOfTen<Ratio<P1000>>, B1>();
+        same::<IsPowerOfTen<Ratio<P10, P1000>>, B1>();
+        same::<IsPowerOfTen<consts::Giga>, B1>();
+        same::<IsPowerOfTen<consts::Nano>, B1>();
+        same::<IsPowerOfTen<Ratio<Z0>>, B0>();
+        same::<IsPowerOfTen<Ratio<N10>>, B0>();
+        same::<IsPowerOfTen<Ratio<P5>>, B0>();
+        same::<IsPowerOfTen<Ratio<P1, P20>>, B0>();
+        same::<IsPowerOfTen<Ratio<P10, P3>>, B0>();
+
+        same::<Log10Exact<Ratio<P1>>, Z0>();
+        same::<Log10Exact<Ratio<P100>>, P2>();
+        same::<Log10Exact<Ratio<P1, P1000>>, N3>();
+        same::<Log10Exact<consts::Peta>, P15>();
+    }
+
+    #[test]
+    fn power_of_two() {
+        same::<IsPowerOfTwo<Ratio<P1>>, B1>();
+        same::<IsPowerOfTwo<Ratio<P64>>, B1>();
+        same::<IsPowerOfTwo<Ratio<P2, P8>>, B1>();
+        same::<IsPowerOfTwo<Ratio<Z0>>, B0>();
+        same::<IsPowerOfTwo<Ratio<N4>>, B0>();
+        same::<IsPowerOfTwo<Ratio<P12>>, B0>();
+        same::<IsPowerOfTwo<Ratio<P2, P3>>, B0>();
+
+        same::<Log2Exact<Ratio<P1>>, Z0>();
+        same::<Log2Exact<Ratio<P1024>>, P10>();
+        same::<Log2Exact<Ratio<P4, P32>>, N3>();
+    }
+
+    #[test]
+    fn is_perfect_square() {
+        same::<IsPerfectSquare<Ratio<P4, P9>>, B1>();
+        same::<IsPerfectSquare<Ratio<P18, P8>>, B1>();
+        same::<IsPerfectSquare<Ratio<Z0>>, B1>();
+        same::<IsPerfectSquare<Ratio<P1>>, B1>();
+        same::<IsPerfectSquare<Ratio<P2>>, B0>();
+        same::<IsPerfectSquare<Ratio<P1, P8>>, B0>();
+        same::<IsPerfectSquare<Ratio<N4>>, B0>();
+    }
+
+    #[test]
+    fn volume() {
+        use consts::{volume_uk::*, volume_us::*};
+
+        assert_eq!(Quot::<ImperialGallon, ImperialPint>::default(), rat!(P8/P1));
+        assert_eq!(Quot::<UsPint, UsFluidOunce>::default(), rat!(P16/P1));
+        assert_eq!(Quot::<ImperialPint, ImperialFluidOunce>::default(), rat!(P20/P1));
+        assert!(UsGallon::default() < ImperialGallon::default());
+    }
+
+    #[test]
+    fn astro() {
+        use consts::astro::*;
+
+        assert_eq!(DynRatio::from(JulianYear::default()), DynRatio::from_integer(31_557_600));
+        assert_eq!(DynRatio::from(Quot::<JulianCentury, JulianDay>::default()),
+                   DynRatio::from_integer(36_525));
+        assert_eq!(DynRatio::from(SiderealDay::default()), DynRatio::new(8_616_409_053, 100_000));
+        assert!(SiderealDay::default() < JulianDay::default());
+    }
+
+    #[test]
+    fn sexagesimal() {
+        use consts::sexagesimal::*;
+
+        assert_eq!(Prod::<ThreeThousandSixHundredth, P60>::default(), Sixtieth::default());
+        assert_eq!(Hms::<P1, P30, Z0>::default(), rat!(P3/P2));
+        assert_eq!(Dms::<N1, N30, Z0>::default(), rat!(N3/P2));
+
+        let x = DynRatio::new(-181, 120);
+        assert_eq!(to_sexagesimal(x), (-1, -30, DynRatio::from_integer(-30)));
+        assert_eq!(from_sexagesimal(-1, -30, DynRatio::from_integer(-30)), x);
+    }
+
+    #[test]
+    fn map_scale() {
+        use consts::map_scale::*;
+
+        assert_eq!(Quot::<Scale1To50000, Scale1To100000>::default(), rat!(P2/P1));
+        assert_eq!(Prod::<Scale1To25000, P1000>::default(), rat!(P1/P25));
+    }
+
+    #[test]
+    fn model_scale() {
+        use consts::model_scale::*;
+
+        assert_eq!(Quot::<Scale1To24, Scale1To72>::default(), rat!(P3/P1));
+        assert!(NScale::default() < HoScale::default());
+    }
+
+    #[test]
+    fn ui_scale() {
+        use consts::ui_scale::*;
+
+        assert_eq!(Prod::<Scale150, P2>::default(), rat!(P3/P1));
+        assert_eq!(Diff::<Scale225, Scale200>::default(), rat!(P1/P4));
+    }
+
+    #[test]
+    fn inverse() {
+        same::<Inverse<Ratio<P3, P4>>, Ratio<P4, P3>>();
+        same::<Inverse<Ratio<P2, N6>>, Ratio<N3, P1>>();
+        same::<Inverse<Inverse<Ratio<P5, P7>>>, Ratio<P5, P7>>();
+    }
+
+    #[test]
+    fn solve_linear() {
+        same::<SolveLinear<Ratio<P3>, Ratio<N2>>, Ratio<P2, P3>>();
+        same::<SolveLinear<Ratio<P1, P2>, Ratio<P3, P4>>, Ratio<N3, P2>>();
+        same::<SolveLinear<Ratio<N2, P4>, Ratio<N1, P3>>, Ratio<N2, P3>>();
+        same::<SolveLinear<Ratio<P5>, Ratio<Z0>>, Ratio<Z0, P1>>();
+    }
+
+    #[test]
+    fn mat2() {
+        type I = RatMat2<Ratio<P1>, Ratio<Z0>, Ratio<Z0>, Ratio<P1>>;
+        type M = RatMat2<Ratio<P1, P2>, Ratio<P1, P3>, Ratio<P3>, Ratio<P4>>;
+        type Rot = RatMat2<Ratio<Z0>, Ratio<N1>, Ratio<P1>, Ratio<Z0>>;
+
+        same::<Determinant<M>, Ratio<P1, P1>>();
+        same::<Determinant<Rot>, Ratio<P1, P1>>();
+        same::<
+            Prod<M, Rot>,
+            RatMat2<Ratio<P1, P3>, Ratio<N1, P2>, Ratio<P4, P1>, Ratio<N3, P1>>,
+        >();
+        same::<
+            Inverse<M>,
+            RatMat2<Ratio<P4, P1>, Ratio<N1, P3>, Ratio<N3, P1>, Ratio<P1, P2>>,
+        >();
+        assert_eq!(Prod::<M, Inverse<M>>::default(), I::default());
+        assert_eq!(Prod::<Rot, Prod<Rot, Prod<Rot, Rot>>>::default(), I::default());
+        assert_eq!(Inverse::<Inverse<M>>::default(), M::default());
+    }
+
+    #[test]
+    fn mobius() {
+        type Recip = Mobius<Ratio<Z0>, Ratio<P1>, Ratio<P1>, Ratio<Z0>>;
+        type Shift = Mobius<Ratio<P1>, Ratio<P1, P2>, Ratio<Z0>, Ratio<P1>>;
+        type M = Mobius<Ratio<P2>, Ratio<P1>, Ratio<P1>, Ratio<P3>>;
+
+        same::<ApplyMobius<Recip, Ratio<N3, P4>>, Ratio<N4, P3>>();
+        same::<ApplyMobius<Shift, Ratio<P1, P3>>, Ratio<P5, P6>>();
+        same::<ApplyMobius<M, Ratio<Z0>>, Ratio<P1, P3>>();
+        same::<ApplyMobius<Prod<Recip, Shift>, Ratio<P1, P3>>, Ratio<P6, P5>>();
+        same::<ApplyMobius<Prod<Shift, Recip>, Ratio<P1, P3>>, Ratio<P7, P2>>();
+        same::<ApplyMobius<Inverse<M>, ApplyMobius<M, Ratio<P5, P7>>>, Ratio<P5, P7>>();
+    }
+
+    #[test]
+    fn interval() {
+        type A = RatInterval<Ratio<N1, P2>, Ratio<P2>>;
+        type B = RatInterval<Ratio<P1, P3>, Ratio<P3, P4>>;
+
+        same::<Sum<A, B>, RatInterval<Ratio<N1, P6>, Ratio<P11, P4>>>();
+        same::<Diff<A, B>, RatInterval<Ratio<N5, P4>, Ratio<P5, P3>>>();
+        same::<Negate<A>, RatInterval<Ratio<N2>, Ratio<P1, P2>>>();
+        same::<Prod<A, B>, RatInterval<Ratio<N3, P8>, Ratio<P3, P2>>>();
+        same::<Prod<A, A>, RatInterval<Ratio<N1, P1>, Ratio<P4, P1>>>();
+        same::<Intersection<A, B>, RatInterval<Ratio<P1, P3>, Ratio<P3, P4>>>();
+        same::<Intersection<A, RatInterval<Ratio<P2>, Ratio<P3>>>, RatInterval<Ratio<P2>, Ratio<P2>>>();
+
+        assert!(Contains::<A, Ratio<N1, P2>>::to_bool());
+        assert!(Contains::<A, Ratio<P4, P2>>::to_bool());
+        assert!(!Contains::<A, Ratio<N2, P3>>::to_bool());
+        assert!(!Contains::<B, Ratio<P4, P5>>::to_bool());
+    }
+
+    #[test]
+    fn in_bounds() {
+        assert!(InBounds::<Ratio<P1, P2>, Ratio<Z0>, Ratio<P1>>::to_bool());
+        assert!(InBounds::<Ratio<Z0>, Ratio<Z0>, Ratio<P1>>::to_bool());
+        assert!(InBounds::<Ratio<P3, P3>, Ratio<Z0>, Ratio<P1>>::to_bool());
+        assert!(!InBounds::<Ratio<N1, P100>, Ratio<Z0>, Ratio<P1>>::to_bool());
+        assert!(!InBounds::<Ratio<P7, P6>, Ratio<N1>, Ratio<P1>>::to_bool());
+        same::<InBounds<Ratio<P2, P3>, Ratio<P1, P2>, Ratio<P3, P4>>, B1>();
+    }
+
+    #[test]
+    fn midpoint() {
+        same::<Midpoint<Ratio<P1, P3>, Ratio<P1, P2>>, Ratio<P5, P12>>();
+        same::<Midpoint<Ratio<P1, P4>, Ratio<P3, P4>>, Ratio<P1, P2>>();
+        same::<Midpoint<Ratio<N3, P2>, Ratio<P1, P2>>, Ratio<N1, P2>>();
+        same::<Midpoint<Ratio<P2, P6>, Ratio<P1, P3>>, Ratio<P1, P3>>();
+    }
+
+    #[test]
+    fn geometric_mean() {
+        same::<GeometricMean<Ratio<P1, P2>, Ratio<P8>>, Ratio<P2, P1>>();
+        same::<GeometricMean<Ratio<P2, P6>, Ratio<P3>>, Ratio<P1, P1>>();
+        same::<GeometricMean<Ratio<N4, P9>, Ratio<N1>>, Ratio<P2, P3>>();
+        same::<GeometricMean<Ratio<Z0>, Ratio<P5, P7>>, Ratio<Z0, P1>>();
+    }
+
+    #[test]
+    fn abs_diff() {
+        same::<AbsVal<Ratio<N3, P6>>, Ratio<P1, P2>>();
+        same::<AbsVal<Ratio<P3, N6>>, Ratio<P1, P2>>();
+        same::<AbsVal<Ratio<Z0>>, Ratio<Z0, P1>>();
+
+        same::<AbsDiff<Ratio<P1, P3>, Ratio<P1, P2>>, Ratio<P1, P6>>();
+        same::<AbsDiff<Ratio<P1, P2>, Ratio<P1, P3>>, Ratio<P1, P6>>();
+        same::<AbsDiff<Ratio<N1>, Ratio<P1, P2>>, Ratio<P3, P2>>();
+        assert!(LeEq::<AbsDiff<Ratio<P22, P7>, Ratio<P314, P100>>, Ratio<P1, P100>>::to_bool());
+    }
+
+    #[test]
+    fn parallel() {
+        assert_eq!(Parallel::<Ratio<P2>, Ratio<P2>>::default(), rat!(P1/P1));
+        assert_eq!(Parallel::<Ratio<P1, P2>, Ratio<P1, P3>>::default(), rat!(P1/P5));
+        assert_eq!(Parallel::<Ratio<P100>, Ratio<P300>>::default(), rat!(P75/P1));
+    }
+
+    #[test]
+    fn divider() {
+        assert_eq!(Divider::<Ratio<P1>, Ratio<P1>>::default(), rat!(P1/P2));
+        assert_eq!(Divider::<Ratio<P47, P10>, Ratio<P10>>::default(), rat!(P47/P147));
+        assert_eq!(DividerComplement::<Ratio<P47, P10>, Ratio<P47, P147>>::default(), rat!(P10/P1));
+        assert_eq!(DividerComplement::<Ratio<P3>, Ratio<P1, P3>>::default(), rat!(P6/P1));
+
+        type E12 = TArr<Ratio<P10>, TArr<Ratio<P12>, TArr<Ratio<P15>, TArr<Ratio<P18>,
+                   TArr<Ratio<P22>, TArr<Ratio<P27>, TArr<Ratio<P33>, TArr<Ratio<P39>, ATerm>>>>>>>>;
+        same::<NearestDividerComplement<Ratio<P10>, Ratio<P2, P7>, E12>, Ratio<P27>>();
+        same::<NearestDividerComplement<Ratio<P47, P10>, Ratio<P47, P147>, E12>, Ratio<P10>>();
+        // 30 is equally near to 27 and 33.
+        same::<NearestDividerComplement<Ratio<P10>, Ratio<P1, P4>, E12>, Ratio<P27>>();
+    }
+
+    #[test]
+    fn nearest_of() {
+        type Steps = TArr<Ratio<P1, P4>, TArr<Ratio<P1, P2>, TArr<Ratio<P1>, ATerm>>>;
+
+        same::<NearestOf<Steps, Ratio<Z0>>, Ratio<P1, P4>>();
+        same::<NearestOf<Steps, Ratio<P3, P8>>, Ratio<P1, P4>>();
+        same::<NearestOf<Steps, Ratio<P5, P8>>, Ratio<P1, P2>>();
+        same::<NearestOf<Steps, Ratio<P7>>, Ratio<P1>>();
+        same::<NearestOf<TArr<Ratio<P2, P4>, ATerm>, Ratio<N3>>, Ratio<P2, P4>>();
+    }
+
+    #[test]
+    fn compound_factor() {
+        assert_eq!(CompoundFactor::<Ratio<P1, P2>, P2>::default(), rat!(P9/P4));
+        assert_eq!(CompoundFactor::<Ratio<P1, P4>, Z0>::default(), rat!(P1/P1));
+        assert_eq!(CompoundFactor::<Ratio<P1>, N1>::default(), rat!(P1/P2));
+    }
+
+    #[test]
+    fn geometric_sum() {
+        assert_eq!(GeometricSum::<Ratio<P2>, U3>::default(), rat!(P15/P1));
+        assert_eq!(GeometricSum::<Ratio<N1, P3>, P2>::default(), rat!(P7/P9));
+        assert_eq!(GeometricSum::<Ratio<P2, P3>, Z0>::default(), rat!(P1/P1));
+    }
+
+    #[test]
+    fn inv_factorials() {
+        same::<InvFactorials<U0>, TArr<Ratio<P1>, ATerm>>();
+        same::<InvFactorials<U4>,
+               TArr<Ratio<P1>, TArr<Ratio<P1>, TArr<Ratio<P1, P2>,
+               TArr<Ratio<P1, P6>, TArr<Ratio<P1, P24>, ATerm>>>>>>();
+    }
+
+    #[test]
+    fn golden_convergent() {
+        assert_eq!(GoldenConvergent::<U1>::default(), rat!(P1/P1));
+        assert_eq!(GoldenConvergent::<U2>::default(), rat!(P2/P1));
+        assert_eq!(GoldenConvergent::<U10>::default(), rat!(P89/P55));
+        assert!(GoldenConvergent::<U11>::default() < GoldenConvergent::<U10>::default());
+    }
+
+    #[test]
+    fn sum_all() {
+        same::<SumAll<ATerm>, Ratio<Z0>>();
+        same::<SumAll<TArr<Ratio<P2, P4>, ATerm>>, Ratio<P1, P2>>();
+        same::<SumAll<TArr<Ratio<P1, P2>, TArr<Ratio<P1, P3>, TArr<Ratio<P1, P6>, ATerm>>>>, Ratio<P1>>();
+        same::<SumAll<ArithSeq<Ratio<P1>, Ratio<P1>, U4>>, Ratio<P10>>();
+        same::<SumAll<TArr<Ratio<N1, P2>, TArr<Ratio<P1, P3>, ATerm>>>, Ratio<N1, P6>>();
+    }
+
+    #[test]
+    fn product_all() {
+        same::<ProductAll<ATerm>, Ratio<P1>>();
+        same::<ProductAll<TArr<Ratio<P2, P4>, ATerm>>, Ratio<P1, P2>>();
+        same::<ProductAll<TArr<Ratio<P2, P3>, TArr<Ratio<P3, P4>, TArr<Ratio<N4, P5>, ATerm>>>>,
+               Ratio<N2, P5>>();
+        same::<ProductAll<GeomSeq<Ratio<P1>, Ratio<P2>, U4>>, Ratio<P64>>();
+        same::<ProductAll<TArr<Ratio<P7>, TArr<Ratio<Z0>, ATerm>>>, Ratio<Z0, P1>>();
+    }
+
+    #[test]
+    fn mean_of() {
+        same::<MeanOf<TArr<Ratio<P2, P4>, ATerm>>, Ratio<P1, P2>>();
+        same::<MeanOf<TArr<Ratio<P1>, TArr<Ratio<P2>, ATerm>>>, Ratio<P3, P2>>();
+        same::<MeanOf<TArr<Ratio<N1, P2>, TArr<Ratio<P1, P2>, ATerm>>>, Ratio<Z0, P1>>();
+        same::<MeanOf<ArithSeq<Ratio<Z0>, Ratio<P1, P3>, U4>>, Ratio<P1, P2>>();
+    }
+
+    #[test]
+    fn dot_product() {
+        type A = TArr<Ratio<P1, P2>, TArr<Ratio<P2, P3>, TArr<Ratio<N1>, ATerm>>>;
+        type B = TArr<Ratio<P2>, TArr<Ratio<P3, P4>, TArr<Ratio<P1, P6>, ATerm>>>;
+
+        same::<DotProduct<ATerm, ATerm>, Ratio<Z0>>();
+        same::<DotProduct<TArr<Ratio<P2, P4>, ATerm>, TArr<Ratio<P2>, ATerm>>, Ratio<P1, P1>>();
+        same::<DotProduct<A, B>, Ratio<P4, P3>>();
+        same::<DotProduct<A, B>, DotProduct<B, A>>();
+    }
+
+    #[test]
+    fn weighted_mean() {
+        type Values = TArr<Ratio<P1, P2>, TArr<Ratio<P3, P2>, ATerm>>;
+
+        same::<WeightedMean<Values, TArr<Ratio<P1>, TArr<Ratio<P1>, ATerm>>>, Ratio<P1, P1>>();
+        same::<WeightedMean<Values, TArr<Ratio<P1, P4>, TArr<Ratio<P3, P4>, ATerm>>>, Ratio<P5, P4>>();
+        same::<WeightedMean<Values, TArr<Ratio<P2>, TArr<Ratio<Z0>, ATerm>>>, Ratio<P1, P2>>();
+        same::<WeightedMean<Values, TArr<Ratio<P1>, TArr<Ratio<P1>, ATerm>>>, MeanOf<Values>>();
+    }
+
+    #[test]
+    fn sorted() {
+        type Rates = TArr<Ratio<P3, P2>, TArr<Ratio<N1, P2>, TArr<Ratio<P2>, TArr<Ratio<N2, P4>, ATerm>>>>;
+
+        same::<Sorted<ATerm>, ATerm>();
+        same::<
+            Sorted<Rates>,
+            TArr<Ratio<N1, P2>, TArr<Ratio<N2, P4>, TArr<Ratio<P3, P2>, TArr<Ratio<P2>, ATerm>>>>,
+        >();
+    }
+
+    #[test]
+    fn min_max_of() {
+        type Rates = TArr<Ratio<P3, P2>, TArr<Ratio<N1, P2>, TArr<Ratio<P2>, TArr<Ratio<N2, P4>, ATerm>>>>;
+
+        same::<MinOf<Rates>, Ratio<N1, P2>>();
+        same::<MaxOf<Rates>, Ratio<P2>>();
+        same::<MinOf<TArr<Ratio<P5, P7>, ATerm>>, Ratio<P5, P7>>();
+        same::<MaxOf<TArr<Ratio<P5, P7>, ATerm>>, Ratio<P5, P7>>();
+    }
+
+    #[test]
+    fn continued_fraction() {
+        same::<ContinuedFraction<Ratio<P415, P93>>, TArr<P4, TArr<P2, TArr<P6, TArr<P7, ATerm>>>>>();
+        same::<ContinuedFraction<Ratio<N7, P3>>, TArr<N3, TArr<P1, TArr<P2, ATerm>>>>();
+        same::<ContinuedFraction<Ratio<P3, P8>>, TArr<Z0, TArr<P2, TArr<P1, TArr<P2, ATerm>>>>>();
+        same::<ContinuedFraction<Ratio<P5>>, TArr<P5, ATerm>>();
+        same::<ContinuedFraction<Ratio<Z0>>, TArr<Z0, ATerm>>();
+    }
+
+    #[test]
+    fn eval_cfrac() {
+        same::<EvalCFrac<TArr<P3, TArr<P7, TArr<P16, ATerm>>>>, Ratio<P355, P113>>();
+        same::<EvalCFrac<TArr<N3, TArr<P1, TArr<P2, ATerm>>>>, Ratio<N7, P3>>();
+        same::<EvalCFrac<TArr<P5, ATerm>>, Ratio<P5>>();
+        same::<EvalCFrac<ContinuedFraction<Ratio<P3, P8>>>, Ratio<P3, P8>>();
+
+        // Non-canonical expansions evaluate to the same ratio.
+        same::<EvalCFrac<TArr<P1, TArr<P1, ATerm>>>, Ratio<P2>>();
+    }
+
+    #[test]
+    fn stern_brocot() {
+        same::<SternBrocotPath<Ratio<P1>>, ATerm>();
+        same::<SternBrocotPath<Ratio<P3>>, TArr<B1, TArr<B1, ATerm>>>();
+        same::<SternBrocotPath<Ratio<P1, P3>>, TArr<B0, TArr<B0, ATerm>>>();
+        same::<SternBrocotPath<Ratio<P6, P10>>, TArr<B0, TArr<B1, TArr<B0, ATerm>>>>();
+
+        same::<FromSternBrocot<ATerm>, Ratio<P1>>();
+        same::<FromSternBrocot<TArr<B1, TArr<B0, ATerm>>>, Ratio<P3, P2>>();
+        same::<FromSternBrocot<SternBrocotPath<Ratio<P7, P11>>>, Ratio<P7, P11>>();
+    }
+
+    #[test]
+    fn calkin_wilf() {
+        same::<NthRational<U0>, Ratio<P1>>();
+        same::<NthRational<U1>, Ratio<P1, P2>>();
+        same::<NthRational<U2>, Ratio<P2>>();
+        same::<NthRational<U3>, Ratio<P1, P3>>();
+        same::<NthRational<U4>, Ratio<P3, P2>>();
+        same::<NthRational<U5>, Ratio<P2, P3>>();
+        same::<NthRational<U6>, Ratio<P3>>();
+        same::<NthRational<U7>, Ratio<P1, P4>>();
+        same::<NthRational<U8>, Ratio<P4, P3>>();
+    }
+
+    #[test]
+    fn sequences() {
+        same::<ArithSeq<Ratio<P1>, Ratio<P1>, U0>, ATerm>();
+        same::<ArithSeq<Ratio<P2, P4>, N1, U3>,
+               TArr<Ratio<P1, P2>, TArr<Ratio<N1, P2>, TArr<Ratio<N3, P2>, ATerm>>>>();
+        same::<GeomSeq<Ratio<P3>, Ratio<N2, P3>, U3>,
+               TArr<Ratio<P3>, TArr<Ratio<N2>, TArr<Ratio<P4, P3>, ATerm>>>>();
+    }
+
+    #[test]
+    fn log_floor() {
+        assert_eq!(LogFloor::<Ratio<P1>, P2>::to_i32(), 0);
+        assert_eq!(LogFloor::<Ratio<P1023>, P2>::to_i32(), 9);
+        assert_eq!(LogFloor::<Ratio<P1024>, P2>::to_i32(), 10);
+        assert_eq!(LogFloor::<Ratio<P1, P20>, P10>::to_i32(), -2);
+        assert_eq!(LogFloor::<Ratio<P1, P10>, P10>::to_i32(), -1);
+        assert_eq!(LogFloor::<Ratio<Prod<P60, P60>>, P60>::to_i32(), 2);
+    }
+
+    #[test]
+    fn next_power_of_two() {
+        assert_eq!(NextPowerOfTwoExponent::<Ratio<P1>>::to_i32(), 0);
+        assert_eq!(NextPowerOfTwoExponent::<Ratio<P1024>>::to_i32(), 10);
+        assert_eq!(NextPowerOfTwoExponent::<Ratio<P1000>>::to_i32(), 10);
+        assert_eq!(NextPowerOfTwoExponent::<Ratio<P3, P4>>::to_i32(), 0);
+        assert_eq!(NextPowerOfTwo::<Ratio<P3, P16>>::default(), rat!(P1/P4));
+        assert_eq!(NextPowerOfTwo::<Ratio<P1, P8>>::default(), rat!(P1/P8));
+    }
+
+    #[test]
+    fn floor_ceil() {
+        same::<Floor<Ratio<P7, P2>>, P3>();
+        same::<Floor<Ratio<N7, P2>>, N4>();
+        same::<Floor<Ratio<P6, P3>>, P2>();
+        same::<Floor<Ratio<Z0>>, Z0>();
+        same::<Floor<Ratio<N1, P3>>, N1>();
+
+        same::<Ceil<Ratio<P7, P2>>, P4>();
+        same::<Ceil<Ratio<N7, P2>>, N3>();
+        same::<Ceil<Ratio<N6, P3>>, N2>();
+        same::<Ceil<Ratio<Z0>>, Z0>();
+        same::<Ceil<Ratio<P1, P3>>, P1>();
+    }
+
+    #[test]
+    fn round() {
+        use rounding::*;
+
+        same::<Round<Ratio<P5, P2>, HalfUp>, P3>();
+        same::<Round<Ratio<N5, P2>, HalfUp>, N3>();
+        same::<Round<Ratio<P4, P3>, HalfUp>, P1>();
+
+        same::<Round<Ratio<P5, P2>, HalfEven>, P2>();
+        same::<Round<Ratio<P7, P2>, HalfEven>, P4>();
+        same::<Round<Ratio<N5, P2>, HalfEven>, N2>();
+        same::<Round<Ratio<N1, P2>, HalfEven>, Z0>();
+        same::<Round<Ratio<P5, P3>, HalfEven>, P2>();
+        same::<Round<Ratio<P3>, HalfEven>, P3>();
+
+        same::<Round<Ratio<P7, P3>, TowardZero>, P2>();
+        same::<Round<Ratio<N7, P3>, TowardZero>, N2>();
+
+        same::<Round<Ratio<P7, P3>, AwayFromZero>, P3>();
+        same::<Round<Ratio<N7, P3>, AwayFromZero>, N3>();
+        same::<Round<Ratio<P6, P3>, AwayFromZero>, P2>();
+        same::<Round<Ratio<Z0>, AwayFromZero>, Z0>();
+    }
+
+    #[test]
+    fn round_to_multiple() {
+        use rounding::*;
+
+        type Quarter = Ratio<P1, P4>;
+
+        assert_eq!(RoundToMultiple::<Ratio<P7, P10>, Quarter, HalfUp>::default(), rat!(P3/P4));
+        assert_eq!(RoundToMultiple::<Ratio<P7, P10>, Quarter, TowardZero>::default(), rat!(P1/P2));
+        assert_eq!(RoundToMultiple::<Ratio<N7, P10>, Quarter, AwayFromZero>::default(), rat!(N3/P4));
+        assert_eq!(RoundToMultiple::<Ratio<P3, P8>, Quarter, HalfUp>::default(), rat!(P1/P2));
+        assert_eq!(RoundToMultiple::<Ratio<P3, P8>, Quarter, HalfEven>::default(), rat!(P1/P2));
+        assert_eq!(RoundToMultiple::<Ratio<P1, P8>, Quarter, HalfEven>::default(), rat!(Z0/P1));
+        assert_eq!(RoundToMultiple::<Ratio<P17>, Ratio<P5>, HalfUp>::default(), rat!(P15/P1));
+    }
+
+    #[test]
+    fn trunc_fract() {
+        same::<Trunc<Ratio<P7, P2>>, P3>();
+        same::<Trunc<Ratio<N7, P2>>, N3>();
+        same::<Trunc<Ratio<N1, P3>>, Z0>();
+
+        same::<Fract<Ratio<P7, P2>>, Ratio<P1, P2>>();
+        same::<Fract<Ratio<N14, P4>>, Ratio<N1, P2>>();
+        same::<Fract<Ratio<P6, P3>>, Ratio<Z0, P1>>();
+
+        assert_eq!(Sum::<Fract<Ratio<N22, P7>>, Trunc<Ratio<N22, P7>>>::default(), rat!(N22/P7));
+    }
+
+    #[test]
+    fn euclid() {
+        same::<DivEuclid<Ratio<P7, P2>, Ratio<P1>>, P3>();
+        same::<DivEuclid<Ratio<N7, P2>, Ratio<P1>>, N4>();
+        same::<DivEuclid<Ratio<P7, P2>, Ratio<N1>>, N3>();
+        same::<DivEuclid<Ratio<N7, P2>, Ratio<N1>>, P4>();
+        same::<DivEuclid<Ratio<P3>, Ratio<P3, P2>>, P2>();
+
+        assert_eq!(RemEuclid::<Ratio<P7, P2>, Ratio<P1>>::default(), rat!(P1/P2));
+        assert_eq!(RemEuclid::<Ratio<N7, P2>, Ratio<P1>>::default(), rat!(P1/P2));
+        assert_eq!(RemEuclid::<Ratio<P7, P2>, Ratio<N1>>::default(), rat!(P1/P2));
+        assert_eq!(RemEuclid::<Ratio<N7, P2>, Ratio<N1>>::default(), rat!(P1/P2));
+        assert_eq!(RemEuclid::<Ratio<N5, P6>, Ratio<P1, P3>>::default(), rat!(P1/P6));
+        assert_eq!(RemEuclid::<Ratio<P3>, Ratio<P3, P2>>::default(), rat!(Z0/P1));
+    }
+
+    #[test]
+    fn round_sig_figs() {
+        assert_eq!(RoundSigFigs::<Ratio<P1, P3>, P1>::default(), rat!(P3/P10));
+        assert_eq!(RoundSigFigs::<Ratio<P15, P1000>, P1>::default(), rat!(P2/P100));
+        assert_eq!(RoundSigFigs::<Ratio<N25, P10>, P1>::default(), rat!(N3/P1));
+        assert_eq!(RoundSigFigs::<Ratio<P999>, P2>::default(), rat!(P1000/P1));
+        assert_eq!(RoundSigFigs::<Ratio<P7, P8>, P3>::default(), rat!(P7/P8));
+    }
+
+    #[test]
+    fn is_exact_float() {
+        assert!(IsExactF64::<Ratio<Z0>>::to_bool());
+        assert!(IsExactF64::<Ratio<P1000000000000000000>>::to_bool());
+        assert!(IsExactF64::<Ratio<Diff<P9007199254740992, P1>, P2>>::to_bool());
+        assert!(!IsExactF64::<Ratio<Sum<P9007199254740992, P1>>>::to_bool());
+
+        assert!(IsExactF32::<Ratio<Diff<P16777216, P1>>>::to_bool());
+        assert!(!IsExactF32::<Ratio<Sum<P16777216, P1>>>::to_bool());
+        assert!(!IsExactF32::<Ratio<P1, P3>>::to_bool());
+    }
+
+    #[test]
+    fn materialize() {
+        const EMPTY: [(i64, i64); 0] = to_pairs::<ATerm, 0>();
+        const PAIRS: [(i64, i64); 3] = to_pairs::<InvFactorials<U2>, 3>();
+        const Q8: [i32; 3] = to_fixed_point::<GeomSeq<Ratio<N1>, Ratio<P1, P3>, U3>, 3, 8>();
+
+        assert_eq!(EMPTY, []);
+        assert_eq!(PAIRS, [(1, 1), (1, 1), (1, 2)]);
+        assert_eq!(Q8, [-256, -85, -28]);
+    }
+
+    #[test]
+    fn to_ratio() {
+        assert_eq!(AsRatio::<Z0>::default(), rat!(Z0/P1));
+        assert_eq!(AsRatio::<N3>::default(), rat!(N3/P1));
+        assert_eq!(AsRatio::<U0>::default(), rat!(Z0/P1));
+        assert_eq!(AsRatio::<U5>::default(), rat!(P5/P1));
+        assert_eq!(AsRatio::<Ratio<P6, N4>>::default(), rat!(N3/P2));
+
+        same::<AsRatio<Dozen>, Ratio<P12, P1>>();
+        same::<AsRatio<N5>, Ratio<N5, P1>>();
+        same::<AsRatio<U7>, Ratio<P7, P1>>();
+    }
+
+    #[test]
+    fn dyn_ratio() {
+        assert_eq!(DynRatio::new(6, -4), DynRatio::new(-3, 2));
+        assert_eq!(DynRatio::from(rat!(P6/N4)), DynRatio::new(-3, 2));
+        assert_eq!(DynRatio::new(0, -7), DynRatio::default());
+
+        assert_eq!(DynRatio::new(1, 3) - DynRatio::new(1, 2), DynRatio::new(-1, 6));
+        assert_eq!(DynRatio::new(2, 3) / DynRatio::new(4, 9), DynRatio::new(3, 2));
+        assert!(DynRatio::new(2, 3) > DynRatio::new(3, 5));
+        assert_eq!(DynRatio::new(-3, 2).to_string(), "-3/2");
+    }
+
+    #[test]
+    fn mul_primitive() {
+        assert_eq!(6i64 * rat!(P2/P3), Some(4));
+        assert_eq!(5i32 * rat!(P2/P3), None);
+        assert_eq!(9u64 * rat!(N1/P3), None);
+        assert_eq!(9i32 * rat!(N1/P3), Some(-3));
+        assert_eq!(1u32 * rat!(Z0/P3), Some(0));
+        assert_eq!(u64::max_value() * rat!(P1/P1), Some(u64::max_value()));
+
+        assert_eq!(rat!(P2/P3) * 6i64, Some(4));
+        assert_eq!(rat!(P2/P3) * 5i32, None);
+        assert_eq!(rat!(N1/P3) * 9i32, Some(-3));
+        assert_eq!(rat!(N1/P3) * 9u64, None);
+        assert_eq!(rat!(P3/P1) * u32::max_value(), None);
+        assert_eq!(rat!(P2/P1) * u64::max_value(), None);
+        assert_eq!(rat!(P1/P2) * u64::max_value(), None);
+        assert_eq!(rat!(N2/P1) * i64::min_value(), None);
+
+        assert_eq!(Ratio::<P2, P3>::scale(6), Some(DynRatio::from_integer(4)));
+        assert_eq!(Ratio::<P2, P3>::scale(5), Some(DynRatio::new(10, 3)));
+        assert_eq!(Ratio::<N1, P3>::scale(9u64), Some(DynRatio::from_integer(-3)));
+        assert_eq!(Ratio::<P3, P1>::scale(u32::max_value()),
+                   Some(DynRatio::from_integer(3 * 4294967295)));
+        assert_eq!(Ratio::<P2, P1>::scale(u64::max_value()), None);
+        assert_eq!(Ratio::<P1, P2>::scale(u64::max_value()), None);
+        assert_eq!(Ratio::<P2, P4>::scale(i64::max_value()),
+                   Some(DynRatio::new(i64::max_value(), 2)));
+    }
+
+    #[test]
+    fn display_percent() {
+        assert_eq!(rat!(P1/P3).display_percent(3).to_string(), "33.333%");
+        assert_eq!(rat!(P2/P3).display_percent(0).to_string(), "67%");
+        assert_eq!(rat!(P999/P1000).display_percent(0).to_string(), "100%");
+        assert_eq!(rat!(N1/P1000).display_percent(0).to_string(), "0%");
+        assert_eq!(rat!(P7/P1).display_percent(2).to_string(), "700.00%");
+    }
+
+    #[test]
+    fn to_string_radix() {
+        assert_eq!(rat!(P7/P1).to_string_radix(2, 8), "111");
+        assert_eq!(rat!(P255/P16).to_string_radix(16, 8), "f.f");
+        assert_eq!(rat!(P1/P6).to_string_radix(10, 8), "0.1(6)");
+        assert_eq!(rat!(P1/P3).to_string_radix(10, 1), "0.(3)");
+        assert_eq!(rat!(N1/P7).to_string_radix(10, 6), "-0.(142857)");
+
+        assert_eq!(rat!(P1/P3).to_string_radix(10, 0), "0...");
+        assert_eq!(rat!(N7/P2).to_string_radix(2, 0), "-11...");
+        assert_eq!(rat!(P12/P3).to_string_radix(10, 0), "4");
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn approx_eq() {
+        use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+
+        assert_relative_eq!(1.0f64 / 3.0, rat!(P1/P3));
+        assert_relative_eq!(rat!(P1/P3), 1.0f32 / 3.0);
+        assert_relative_ne!(0.333f64, rat!(P1/P3));
+        assert_abs_diff_eq!(0.333f64, rat!(P1/P3), epsilon = 0.001);
+        assert!(0.5f64 == rat!(P1/P2));
+        assert!(1.0f64 / 3.0 != rat!(P1/P3));
+    }
+
+    #[cfg(feature = "malachite-q")]
+    #[test]
+    fn malachite() {
+        use malachite_q::Rational as BigRational;
+
+        assert_eq!(BigRational::from(rat!(N6/P4)), BigRational::from_signeds(-3i64, 2));
+        assert!(rat!(P1/P3) < BigRational::from_signeds(1i64, 2));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn rug() {
+        use rug::Rational as BigRational;
+
+        assert_eq!(BigRational::from(rat!(N6/P4)), BigRational::from((-3, 2)));
+        assert!(DynRatio::new(1, 3) < BigRational::from((1, 2)));
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn arbitrary() {
+        use proptest::strategy::{Strategy, ValueTree};
+        use proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::default();
+        for _ in 0..100 {
+            let mut tree = arbitrary::ratios(10, 4).new_tree(&mut runner).unwrap();
+            let r = tree.current();
+            assert!(r.numer().abs() <= 10 && r.denom() <= 4);
+
+            while tree.simplify() {}
+            assert_eq!(tree.current(), DynRatio::new(0, 1));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn compact_ratio() {
+        for &(n, d) in &[(0, 1), (-1, 3), (63, 64), (i64::max_value(), 1), (i64::min_value(), 1)] {
+            let r = CompactRatio(DynRatio::new(n, d));
+            assert_eq!(CompactRatio::from_bytes(&r.to_bytes()), Some(r));
+        }
+
+        assert_eq!(CompactRatio(DynRatio::new(-1, 3)).to_bytes(), vec![0x01, 0x03]);
+        assert_eq!(CompactRatio(DynRatio::new(i64::min_value(), 1)).to_bytes().len(), 11);
+        assert_eq!(CompactRatio::from_bytes(&[0x02, 0x00]), None);
+        assert_eq!(CompactRatio::from_bytes(&[0x02, 0x01, 0x00]), None);
+        assert_eq!(CompactRatio::from_bytes(&[0x80]), None);
+    }
+
+    #[test]
+    fn ratio_range() {
+        assert_eq!(RatioRange::<Ratio<P1>, Ratio<N1>, Ratio<N3, P4>>::iter().collect::<Vec<_>>(),
+                   vec![DynRatio::new(1, 1), DynRatio::new(1, 4), DynRatio::new(-1, 2)]);
+        assert_eq!(RatioRange::<Ratio<P1>, Ratio<Z0>, Ratio<P1, P2>>::iter().count(), 0);
+        assert_eq!(RatioRange::<Ratio<Z0>, Ratio<P1>, Ratio<P1, P10>>::iter().last(),
+                   Some(DynRatio::new(1, 1)));
+    }
+
+    #[test]
+    fn farey() {
+        assert_eq!(FareySequence::<U1>::new().collect::<Vec<_>>(),
+                   vec![DynRatio::new(0, 1), DynRatio::new(1, 1)]);
+        assert_eq!(FareySequence::<U5>::new().count(), 11);
+        assert!(FareySequence::<U7>::new().zip(FareySequence::<U7>::new().skip(1)).all(|(a, b)| a < b));
+    }
+
+    assert_rat_approx!(Ratio<P2, P3>, 0.6667, tol = Ratio<P1, P1000>);
+    assert_rat_approx!(Ratio<N7, P4>, -1.75, tol = Ratio<Z0>);
+
+    #[test]
+    fn blend() {
+        assert_eq!(color::blend::<Ratio<Z0>>(10, 20), 20);
+        assert_eq!(color::blend::<Ratio<P1>>(10, 20), 10);
+        assert_eq!(color::blend::<Ratio<P1, P3>>(0, 255), 170);
+        assert_eq!(color::blend::<Ratio<P1, P2>>(0, 1), 1);
+    }
+
+    #[test]
+    fn pwm() {
+        assert_eq!(pwm::compare_value::<Ratio<Z0>, U255>(), 0);
+        assert_eq!(pwm::compare_value::<Ratio<P1>, U255>(), 255);
+        assert_eq!(pwm::compare_value::<Ratio<P1, P2>, U255>(), 128);
+        assert_eq!(pwm::compare_value::<Ratio<P2, P3>, U1000>(), 667);
+    }
+
+    #[test]
+    fn video_fit() {
+        use video::Fit;
+
+        assert_eq!(video::fit::<Ratio<P16, P9>>(1920, 1080),
+                   Fit { width: 1920, height: 1080, left: 0, right: 0, top: 0, bottom: 0 });
+        assert_eq!(video::fit::<Ratio<P16, P9>>(1440, 1080),
+                   Fit { width: 1440, height: 810, left: 0, right: 0, top: 135, bottom: 135 });
+        assert_eq!(video::fit::<Ratio<P21, P9>>(1001, 1000),
+                   Fit { width: 1000, height: 428, left: 0, right: 1, top: 286, bottom: 286 });
+    }
+
+    mod approx {
+        use super::*;
+        use consts::approx::*;
+        use std::f64::consts::{E, PI};
+
+        assert_rat_approx!(Pi22Over7, PI, tol = Ratio<P13, P10000>);
+        assert_rat_approx!(Pi333Over106, PI, tol = Ratio<P1, P10000>);
+        assert_rat_approx!(Pi355Over113, PI, tol = Ratio<P3, P10000000>);
+
+        assert_rat_approx!(E19Over7, E, tol = Ratio<P4, P1000>);
+        assert_rat_approx!(E87Over32, E, tol = Ratio<P5, P10000>);
+        assert_rat_approx!(E2721Over1001, E, tol = Ratio<P2, P10000000>);
+    }
+
+    #[test]
+    fn div_exact() {
+        assert_eq!(12i32.div_exact::<Ratio<P4, P3>>(), Ok(9));
+        assert_eq!((-12i64).div_exact::<Ratio<N4, P3>>(), Ok(9));
+        assert_eq!(7u8.div_exact::<Ratio<P1, P2>>(), Ok(14));
+        assert_eq!(u64::max_value().div_exact::<Ratio<P3>>(), Ok(6_148_914_691_236_517_205));
+        assert_eq!(10usize.div_exact::<Ratio<P5, P2>>(), Ok(4));
+        assert_eq!((-7i64).div_exact::<Ratio<P2>>(),
+                   Err(Remainder { quotient: -3, remainder: DynRatio::from_integer(-1) }));
+        assert_eq!(1i64.div_exact::<Ratio<P3, P2>>(),
+                   Err(Remainder { quotient: 0, remainder: DynRatio::from_integer(1) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "quotient does not fit in an `i64`")]
+    fn div_exact_overflow() {
+        let _ = i64::max_value().div_exact::<Ratio<P1, P2>>();
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(rat!(N3/P6).to_string(), "-1/2");
+        assert_eq!(rat!(Z0/P6).to_string(), "0/1");
+        assert_eq!(format!("{:?}", rat!(P1000000000/P3)), "1000000000/3");
+
+        type Big = Prod<P1000000000000000000, P1000000000000000000>;
+        assert_eq!(Ratio::<Big, P7>::default().to_string(),
+                   "1000000000000000000000000000000000000/7");
+        assert_eq!(Ratio::<P1, Prod<Big, N3>>::default().to_string(),
+                   "-1/3000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn to_f64_with_error() {
+        assert_eq!(rat!(N5/P2).to_f64_with_error(), (-2.5, (DynRatio::default(), 0)));
+        assert_eq!(rat!(P1000/P1).to_f64_with_error(), (1000.0, (DynRatio::default(), 0)));
+
+        assert_eq!(rat!(P1/P3).to_f64_with_error(), (1.0 / 3.0, (DynRatio::new(1, 3), -54)));
+        assert_eq!(rat!(N2/P3).to_f64_with_error(), (-2.0 / 3.0, (DynRatio::new(-1, 3), -53)));
+
+        // The scaled errors of these do not fit in a single `DynRatio`.
+        assert_eq!(rat!(P1/P100).to_f64_with_error(), (0.01, (DynRatio::new(-3, 25), -59)));
+        assert_eq!(rat!(P1/P1000).to_f64_with_error(), (0.001, (DynRatio::new(-12, 125), -62)));
+        assert_eq!(DynRatio::new(1, 999).to_f64_with_error(),
+                   (1.0 / 999.0, (DynRatio::new(40, 999), -62)));
+
+        // Both the numerator and denominator are rounded by `to_f64`.
+        assert_eq!(DynRatio::new(i64::max_value(), 3).to_f64_with_error(),
+                   (3074457345618258432.0, (DynRatio::new(511, 1536), 9)));
+        assert_eq!(DynRatio::new(1, i64::max_value()).to_f64_with_error().1,
+                   (DynRatio::new(4503599627370496, i64::max_value()), -115));
+    }
+
+    #[test]
+    fn rate_limit() {
+        use rate_limit::RateLimiter;
+
+        let mut limiter = RateLimiter::<Ratio<P10>, Ratio<P5, P2>>::new(1_000);
+        assert_eq!(limiter.available(1_000), 2);
+        assert!(limiter.try_acquire(1_000, 2));
+        assert!(!limiter.try_acquire(1_000, 1));
+
+        // The remaining half token plus 1/20s of refill gives exactly one token.
+        assert_eq!(limiter.available(50_000_999), 0);
+        assert!(limiter.try_acquire(50_001_000, 1));
+        assert_eq!(limiter.available(10_000_000_000), 2);
+    }
+
+    #[test]
+    fn rounding_modes() {
+        use rounding::*;
+
+        let cases = [(5, 2), (-5, 2), (7, 2), (4, 3), (-5, 3)];
+        let round = |f: fn(i128, i128) -> i128| cases.iter().map(|&(n, d)| f(n, d)).collect::<Vec<_>>();
+        assert_eq!(round(TowardZero::round_div), vec![2, -2, 3, 1, -1]);
+        assert_eq!(round(AwayFromZero::round_div), vec![3, -3, 4, 2, -2]);
+        assert_eq!(round(HalfUp::round_div), vec![3, -3, 4, 1, -2]);
+        assert_eq!(round(HalfEven::round_div), vec![2, -2, 4, 1, -2]);
+    }
+
+    #[test]
+    fn percent() {
+        use percent::Percent;
+        use rounding::HalfEven;
+
+        assert_eq!(Percent::<Ratio<P50>>::apply(5u8), 3);
+        assert_eq!(Percent::<Ratio<P50>, HalfEven>::apply(5u8), 2);
+        assert_eq!(Percent::<Ratio<P50>>::apply(-5i64), -3);
+        assert_eq!(Percent::<Ratio<P100>>::apply(255u8), 255);
+        assert_eq!(Percent::<Ratio<P1, P3>>::apply_with_remainder(1000i32),
+                   (3, DynRatio::new(1, 3)));
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(3.0f64 * rat!(P1/P3), 1.0);
+        assert_eq!(1.5f32 * rat!(N2/P3), -1.0);
+        assert_eq!(1.0f64 / rat!(P1/P4), 4.0);
+        assert_eq!(0.1f64 * rat!(P10/P1), 1.0);
+        assert_eq!(0.7f64 * rat!(P7/P10), 0.49);
+        assert_eq!(rat!(P7/P10) * 0.7f64, 0.49);
+        assert_eq!(rat!(P3/P4) * 2.0f32, 1.5);
+        assert_eq!(rat!(P3/P4) / 0.5f64, 1.5);
+        assert_eq!(rat!(N1/P3) / 2.0f32, -1.0 / 6.0);
+
+        assert_eq!(1.0f64 / rat!(Z0/P1), f64::INFINITY);
+        assert_eq!(-2.0f32 / rat!(Z0/P1), f32::NEG_INFINITY);
+        assert_eq!(rat!(N1/P2) / 0.0f64, f64::NEG_INFINITY);
+        assert!((0.0f64 / rat!(Z0/P1)).is_nan());
     }
 }
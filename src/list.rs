@@ -0,0 +1,342 @@
+//! Type operators which produce or consume type-level sequences of ratios.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use typenum::{
+    Abs,
+    B1, Bit,
+    Cmp, Equal, Greater, Less,
+    Max, Min,
+    Integer,
+    P1, Z0,
+    UInt, UTerm,
+    Unsigned,
+    array::{ATerm, TArr},
+    operator_aliases::*,
+};
+
+use super::{Ratio, Rational, private::*, type_operators::*};
+use super::operator_aliases::{
+    AbsDiff, DotProduct, MaxOf, MinOf, NearestOf, ProductAll, Reduce, Sorted, SumAll,
+};
+
+/// A type-level list of [`Rational`]s which can be materialized during constant evaluation.
+///
+/// This is implemented for every `typenum` `TArr` whose elements are [`Rational`]. See
+/// [`to_pairs`] and [`to_fixed_point`].
+///
+/// [`Rational`]: ./trait.Rational.html
+/// [`to_pairs`]: ./fn.to_pairs.html
+/// [`to_fixed_point`]: ./fn.to_fixed_point.html
+pub trait RatioList {
+    #[doc(hidden)]
+    const NODE: RatioNode;
+}
+
+impl RatioList for ATerm {
+    const NODE: RatioNode = RatioNode::Nil;
+}
+
+impl<V, A> RatioList for TArr<V, A>
+    where V: Rational,
+          A: RatioList,
+{
+    const NODE: RatioNode = RatioNode::Cons(
+        (<V::Num as Integer>::I64, <V::Den as Integer>::I64),
+        &A::NODE,
+    );
+}
+
+/// Converts a type-level list of ratios into an array of reduced `(numerator, denominator)` pairs.
+///
+/// This is a `const fn`, so the result can initialize a `const` or `static` lookup table. `N` must
+/// be equal to the length of the list; anything else fails to compile when used in a constant.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*, to_pairs};
+///
+/// const TABLE: [(i64, i64); 4] = to_pairs::<ArithSeq<Ratio<Z0>, Ratio<P1, P3>, U4>, 4>();
+/// assert_eq!(TABLE, [(0, 1), (1, 3), (2, 3), (1, 1)]);
+/// ```
+pub const fn to_pairs<L: RatioList, const N: usize>() -> [(i64, i64); N] {
+    let mut out = [(0, 0); N];
+    let mut node = &L::NODE;
+    let mut i = 0;
+    while i < N {
+        match node {
+            RatioNode::Cons(pair, rest) => {
+                out[i] = *pair;
+                node = *rest;
+            }
+            RatioNode::Nil => panic!("list is shorter than the output array"),
+        }
+        i += 1;
+    }
+
+    if let RatioNode::Cons(..) = node {
+        panic!("list is longer than the output array");
+    }
+
+    out
+}
+
+/// Converts a type-level list of ratios into an array of fixed-point coefficients with `F`
+/// fractional bits.
+///
+/// Each coefficient is `round(R * 2^F)`, with ties rounded away from zero. As with [`to_pairs`],
+/// `N` must be equal to the length of the list, and a coefficient which does not fit in an `i32`
+/// fails to compile when used in a constant.
+///
+/// [`to_pairs`]: ./fn.to_pairs.html
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, to_fixed_point};
+///
+/// // Q15 filter taps
+/// const TAPS: [i32; 3] = to_fixed_point::<tarr![Ratio<P1, P4>, Ratio<P1, P2>, Ratio<N1, P3>], 3, 15>();
+/// assert_eq!(TAPS, [8192, 16384, -10923]);
+/// ```
+pub const fn to_fixed_point<L: RatioList, const N: usize, const F: u32>() -> [i32; N] {
+    let pairs = to_pairs::<L, N>();
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        let (num, den) = (pairs[i].0 as i128, pairs[i].1 as i128);
+        let scaled = num * (1 << F);
+        let rounded = if scaled < 0 {
+            (2 * scaled - den) / (2 * den)
+        } else {
+            (2 * scaled + den) / (2 * den)
+        };
+
+        if rounded < i32::MIN as i128 || rounded > i32::MAX as i128 {
+            panic!("fixed-point coefficient does not fit in an `i32`");
+        }
+
+        out[i] = rounded as i32;
+        i += 1;
+    }
+
+    out
+}
+
+impl<N> TaylorCoefficients for N
+    where N: Unsigned + PrivateInvFactorials<Ratio<P1>, P1>,
+{
+    type Output = <N as PrivateInvFactorials<Ratio<P1>, P1>>::Output;
+}
+
+impl<Term, K> PrivateInvFactorials<Term, K> for UTerm {
+    type Output = TArr<Term, ATerm>;
+}
+
+/// Term/(K-1)! => Term/(K-1)!, Term/K!, ...
+impl<U, B, Term, K> PrivateInvFactorials<Term, K> for UInt<U, B>
+    where U: Unsigned,
+          B: Bit,
+          UInt<U, B>: Sub<B1>,
+          Term: Div<K>,
+          K: Add<P1>,
+          Sub1<UInt<U, B>>: PrivateInvFactorials<Quot<Term, K>, Sum<K, P1>>,
+{
+    type Output = TArr<
+        Term,
+        <Sub1<UInt<U, B>> as PrivateInvFactorials<Quot<Term, K>, Sum<K, P1>>>::Output,
+    >;
+}
+
+impl<N> FibonacciRatio for N
+    where N: Unsigned + PrivateFibonacci<Z0, P1>,
+{
+    type Output = <N as PrivateFibonacci<Z0, P1>>::Output;
+}
+
+/// Consecutive Fibonacci numbers are coprime, so `B/A` needs no reduction.
+impl<A, B> PrivateFibonacci<A, B> for UTerm {
+    type Output = Ratio<B, A>;
+}
+
+/// (A, B) => (B, A + B)
+impl<U, Bt, A, B> PrivateFibonacci<A, B> for UInt<U, Bt>
+    where U: Unsigned,
+          Bt: Bit,
+          UInt<U, Bt>: Sub<B1>,
+          A: Add<B>,
+          Sub1<UInt<U, Bt>>: PrivateFibonacci<B, Sum<A, B>>,
+{
+    type Output = <Sub1<UInt<U, Bt>> as PrivateFibonacci<B, Sum<A, B>>>::Output;
+}
+
+impl<N, D, Step, Len> ArithmeticSequence<Step, Len> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Len: PrivateArithSeq<Reduce<N, D>, Step>,
+{
+    type Output = <Len as PrivateArithSeq<Reduce<N, D>, Step>>::Output;
+}
+
+impl<Start, Step> PrivateArithSeq<Start, Step> for UTerm {
+    type Output = ATerm;
+}
+
+/// Start, Step => Start, Start + Step, ...
+impl<U, B, Start, Step> PrivateArithSeq<Start, Step> for UInt<U, B>
+    where U: Unsigned,
+          B: Bit,
+          UInt<U, B>: Sub<B1>,
+          Start: Add<Step>,
+          Sub1<UInt<U, B>>: PrivateArithSeq<Sum<Start, Step>, Step>,
+{
+    type Output = TArr<Start, <Sub1<UInt<U, B>> as PrivateArithSeq<Sum<Start, Step>, Step>>::Output>;
+}
+
+impl<N, D, Factor, Len> GeometricSequence<Factor, Len> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Len: PrivateGeomSeq<Reduce<N, D>, Factor>,
+{
+    type Output = <Len as PrivateGeomSeq<Reduce<N, D>, Factor>>::Output;
+}
+
+impl<Start, Factor> PrivateGeomSeq<Start, Factor> for UTerm {
+    type Output = ATerm;
+}
+
+/// Start, Factor => Start, Start * Factor, ...
+impl<U, B, Start, Factor> PrivateGeomSeq<Start, Factor> for UInt<U, B>
+    where U: Unsigned,
+          B: Bit,
+          UInt<U, B>: Sub<B1>,
+          Start: Mul<Factor>,
+          Sub1<UInt<U, B>>: PrivateGeomSeq<Prod<Start, Factor>, Factor>,
+{
+    type Output = TArr<Start, <Sub1<UInt<U, B>> as PrivateGeomSeq<Prod<Start, Factor>, Factor>>::Output>;
+}
+
+/// The empty sum is zero.
+impl SumList for ATerm {
+    type Output = Ratio<Z0>;
+}
+
+impl<V, A> SumList for TArr<V, A>
+    where A: SumList,
+          V: Add<SumAll<A>>,
+{
+    type Output = Sum<V, SumAll<A>>;
+}
+
+/// The empty product is one.
+impl ProductList for ATerm {
+    type Output = Ratio<P1>;
+}
+
+impl<V, A> ProductList for TArr<V, A>
+    where A: ProductList,
+          V: Mul<ProductAll<A>>,
+{
+    type Output = Prod<V, ProductAll<A>>;
+}
+
+impl InnerProduct<ATerm> for ATerm {
+    type Output = Ratio<Z0>;
+}
+
+impl<V, A, W, B> InnerProduct<TArr<W, B>> for TArr<V, A>
+    where A: InnerProduct<B>,
+          V: Mul<W>,
+          Prod<V, W>: Add<DotProduct<A, B>>,
+{
+    type Output = Sum<Prod<V, W>, DotProduct<A, B>>;
+}
+
+impl<V> MinimumList for TArr<V, ATerm> {
+    type Output = V;
+}
+
+impl<V, W, A> MinimumList for TArr<V, TArr<W, A>>
+    where TArr<W, A>: MinimumList,
+          V: Min<MinOf<TArr<W, A>>>,
+{
+    type Output = Minimum<V, MinOf<TArr<W, A>>>;
+}
+
+impl<V> MaximumList for TArr<V, ATerm> {
+    type Output = V;
+}
+
+impl<V, W, A> MaximumList for TArr<V, TArr<W, A>>
+    where TArr<W, A>: MaximumList,
+          V: Max<MaxOf<TArr<W, A>>>,
+{
+    type Output = Maximum<V, MaxOf<TArr<W, A>>>;
+}
+
+impl<V, X> NearestInList<X> for TArr<V, ATerm> {
+    type Output = V;
+}
+
+type NearestOfTail<W, A, X> = NearestOf<TArr<W, A>, X>;
+type CompareNearness<V, T, X> = Compare<AbsDiff<V, X>, AbsDiff<T, X>>;
+
+/// The head is chosen unless the nearest element of the tail is strictly nearer to `X`.
+impl<V, W, A, X> NearestInList<X> for TArr<V, TArr<W, A>>
+    where TArr<W, A>: NearestInList<X>,
+          V: Sub<X>,
+          NearestOfTail<W, A, X>: Sub<X>,
+          Diff<V, X>: Abs,
+          Diff<NearestOfTail<W, A, X>, X>: Abs,
+          AbsDiff<V, X>: Cmp<AbsDiff<NearestOfTail<W, A, X>, X>>,
+          V: PrivateMin<NearestOfTail<W, A, X>, CompareNearness<V, NearestOfTail<W, A, X>, X>>,
+{
+    type Output =
+        <V as PrivateMin<NearestOfTail<W, A, X>, CompareNearness<V, NearestOfTail<W, A, X>, X>>>
+            ::Output;
+}
+
+impl SortList for ATerm {
+    type Output = ATerm;
+}
+
+/// Insertion sort: sort the tail, then insert the head into it.
+impl<V, A> SortList for TArr<V, A>
+    where A: SortList,
+          Sorted<A>: PrivateInsert<V>,
+{
+    type Output = <Sorted<A> as PrivateInsert<V>>::Output;
+}
+
+impl<V> PrivateInsert<V> for ATerm {
+    type Output = TArr<V, ATerm>;
+}
+
+impl<V, W, A> PrivateInsert<V> for TArr<W, A>
+    where V: Cmp<W>,
+          TArr<W, A>: PrivateInsertOrd<V, Compare<V, W>>,
+{
+    type Output = <TArr<W, A> as PrivateInsertOrd<V, Compare<V, W>>>::Output;
+}
+
+impl<V, W, A> PrivateInsertOrd<V, Less> for TArr<W, A> {
+    type Output = TArr<V, TArr<W, A>>;
+}
+
+/// Inserting before an equal element keeps the sort stable, since `V` came first.
+impl<V, W, A> PrivateInsertOrd<V, Equal> for TArr<W, A> {
+    type Output = TArr<V, TArr<W, A>>;
+}
+
+impl<V, W, A> PrivateInsertOrd<V, Greater> for TArr<W, A>
+    where A: PrivateInsert<V>,
+{
+    type Output = TArr<W, <A as PrivateInsert<V>>::Output>;
+}
@@ -0,0 +1,146 @@
+//! Logarithms of ratios, computed by repeated multiplication or division by the base.
+
+use std::ops::{Add, BitAnd, Mul, Sub};
+
+use typenum::{
+    B0, B1,
+    NInt, PInt,
+    NonZero,
+    P1, P2, Z0,
+    Unsigned,
+    consts::U1,
+    operator_aliases::*,
+    type_operators::*,
+};
+
+use super::{Ratio, Rational, private::*, type_operators::*};
+use super::operator_aliases::{Num, Den, LogFloor};
+
+type Floor2<N, D> = LogFloor<Ratio<N, D>, P2>;
+type FloorPow2<N, D> = Exp<Ratio<P2>, Floor2<N, D>>;
+type IsPow2<N, D> = And<
+    Eq<<FloorPow2<N, D> as Rational>::Num, Num<N, D>>,
+    Eq<<FloorPow2<N, D> as Rational>::Den, Den<N, D>>,
+>;
+
+/// log_B(N/D), for N/D > 0 and B > 1
+impl<N, D, B> IntegerLog<B> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          B: IsGreater<P1, Output = B1>,
+          Num<N, D>: IsGreater<Z0, Output = B1>
+                   + IsLess<Den<N, D>>
+                   + PrivateLogFloor<Den<N, D>, B, Le<Num<N, D>, Den<N, D>>>,
+{
+    type Output = <Num<N, D> as PrivateLogFloor<Den<N, D>, B, Le<Num<N, D>, Den<N, D>>>>::Output;
+}
+
+/// N/D >= 1
+impl<N, D, B> PrivateLogFloor<D, B, B0> for N
+    where B: Mul<D>,
+          N: IsGreaterOrEqual<Prod<B, D>>
+           + PrivateLogUp<D, B, Z0, GrEq<N, Prod<B, D>>>,
+{
+    type Output = <N as PrivateLogUp<D, B, Z0, GrEq<N, Prod<B, D>>>>::Output;
+}
+
+/// N/D < 1
+impl<N, D, B> PrivateLogFloor<D, B, B1> for N
+    where N: PrivateLogDown<D, B, Z0, B1>,
+{
+    type Output = <N as PrivateLogDown<D, B, Z0, B1>>::Output;
+}
+
+/// N/D >= B => log_B(N/D) = log_B(N/(B*D)) + 1
+impl<N, D, B, K> PrivateLogUp<D, B, K, B1> for N
+    where B: Mul<D> + Mul<Prod<B, D>>,
+          K: Add<P1>,
+          N: IsGreaterOrEqual<Prod<B, Prod<B, D>>>
+           + PrivateLogUp<Prod<B, D>, B, Sum<K, P1>, GrEq<N, Prod<B, Prod<B, D>>>>,
+{
+    type Output = <N as PrivateLogUp<Prod<B, D>, B, Sum<K, P1>, GrEq<N, Prod<B, Prod<B, D>>>>>::Output;
+}
+
+/// 1 <= N/D < B
+impl<N, D, B, K> PrivateLogUp<D, B, K, B0> for N {
+    type Output = K;
+}
+
+/// N/D < 1 => log_B(N/D) = log_B(N*B/D) - 1
+impl<N, D, B, K> PrivateLogDown<D, B, K, B1> for N
+    where N: Mul<B>,
+          K: Sub<P1>,
+          Prod<N, B>: IsLess<D> + PrivateLogDown<D, B, Diff<K, P1>, Le<Prod<N, B>, D>>,
+{
+    type Output = <Prod<N, B> as PrivateLogDown<D, B, Diff<K, P1>, Le<Prod<N, B>, D>>>::Output;
+}
+
+/// 1 <= N/D
+impl<N, D, B, K> PrivateLogDown<D, B, K, B0> for N {
+    type Output = K;
+}
+
+/// ceil(log2(R)) = floor(log2(R)) if R = 2^floor(log2(R)), floor(log2(R)) + 1 otherwise
+impl<N, D> CeilLog2 for Ratio<N, D>
+    where Ratio<N, D>: Rational + IntegerLog<P2>,
+          Ratio<P2>: Pow<Floor2<N, D>>,
+          FloorPow2<N, D>: Rational,
+          <FloorPow2<N, D> as Rational>::Num: IsEqual<Num<N, D>>,
+          <FloorPow2<N, D> as Rational>::Den: IsEqual<Den<N, D>>,
+          Eq<<FloorPow2<N, D> as Rational>::Num, Num<N, D>>:
+              BitAnd<Eq<<FloorPow2<N, D> as Rational>::Den, Den<N, D>>>,
+          Floor2<N, D>: PrivateCeilLog<IsPow2<N, D>>,
+{
+    type Output = <Floor2<N, D> as PrivateCeilLog<IsPow2<N, D>>>::Output;
+}
+
+impl<K> PrivateCeilLog<B1> for K {
+    type Output = K;
+}
+
+impl<K> PrivateCeilLog<B0> for K
+    where K: Add<P1>,
+{
+    type Output = Sum<K, P1>;
+}
+
+type Mag<I> = <I as PrivateMagnitude>::Output;
+type WithoutBase<I, B> = <Mag<I> as PrivateWithoutFactor<Mag<B>>>::Output;
+
+impl<N, D, B> PowerOfBase<B> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          B: IsGreater<P1, Output = B1>,
+          Num<N, D>: PrivatePowerOf<B, Den<N, D>>,
+{
+    type Output = <Num<N, D> as PrivatePowerOf<B, Den<N, D>>>::Output;
+}
+
+impl<B, D> PrivatePowerOf<B, D> for Z0 {
+    type Output = B0;
+}
+
+impl<U, B, D> PrivatePowerOf<B, D> for NInt<U>
+    where U: Unsigned + NonZero,
+{
+    type Output = B0;
+}
+
+/// N/D = B^k iff one of N and D is a power of B and the other is one, since N/D is reduced
+impl<U, B, D> PrivatePowerOf<B, D> for PInt<U>
+    where U: Unsigned + NonZero,
+          PInt<U>: PrivateMagnitude,
+          B: PrivateMagnitude,
+          D: PrivateMagnitude,
+          Mag<PInt<U>>: PrivateWithoutFactor<Mag<B>>,
+          Mag<D>: PrivateWithoutFactor<Mag<B>>,
+          WithoutBase<PInt<U>, B>: IsEqual<U1>,
+          WithoutBase<D, B>: IsEqual<U1>,
+          Eq<WithoutBase<PInt<U>, B>, U1>: BitAnd<Eq<WithoutBase<D, B>, U1>>,
+{
+    type Output = And<Eq<WithoutBase<PInt<U>, B>, U1>, Eq<WithoutBase<D, B>, U1>>;
+}
+
+impl<N, D, B> ExactLog<B> for Ratio<N, D>
+    where Ratio<N, D>: PowerOfBase<B, Output = B1> + IntegerLog<B>,
+{
+    type Output = LogFloor<Ratio<N, D>, B>;
+}
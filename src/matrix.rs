@@ -0,0 +1,191 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::*;
+
+use typenum::operator_aliases::*;
+
+use super::{type_operators::*, operator_aliases::{Determinant, Inverse}};
+use super::ratio::Ratio;
+
+/// A 2×2 matrix whose entries are [`Ratio`]s known at compile time.
+///
+/// The matrix is written in row-major order, so `RatMat2<A, B, C, D>` is
+///
+/// ```text
+/// | A  B |
+/// | C  D |
+/// ```
+///
+/// Matrices can be multiplied with `*`, and a non-singular matrix can be inverted with
+/// [`Inverse`]. The entries of a product or inverse are reduced. See also [`Determinant`].
+///
+/// # Example
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, RatMat2, operator_aliases::*};
+///
+/// // Scale x by 3/2, then shear y by half of x.
+/// type Scale = RatMat2<Ratio<P3, P2>, Ratio<Z0>, Ratio<Z0>, Ratio<P1>>;
+/// type Shear = RatMat2<Ratio<P1>, Ratio<Z0>, Ratio<P1, P2>, Ratio<P1>>;
+///
+/// type Transform = Prod<Shear, Scale>;
+/// assert_eq!(Transform::default(),
+///            RatMat2::<Ratio<P3, P2>, Ratio<Z0>, Ratio<P3, P4>, Ratio<P1>>::default());
+/// assert_eq!(Determinant::<Transform>::default(), Ratio::<P3, P2>::default());
+/// assert_eq!(Prod::<Transform, Inverse<Transform>>::default(),
+///            RatMat2::<Ratio<P1>, Ratio<Z0>, Ratio<Z0>, Ratio<P1>>::default());
+/// ```
+///
+/// A singular matrix has no inverse:
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, RatMat2, operator_aliases::*};
+///
+/// let _ = Inverse::<RatMat2<Ratio<P1>, Ratio<P2>, Ratio<P2>, Ratio<P4>>>::default();
+/// ```
+///
+/// [`Ratio`]: ./struct.Ratio.html
+/// [`Inverse`]: ./operator_aliases/type.Inverse.html
+/// [`Determinant`]: ./operator_aliases/type.Determinant.html
+pub struct RatMat2<A, B, C, D>(PhantomData<(A, B, C, D)>);
+
+/// The Möbius transformation `x => (A*x + B)/(C*x + D)`.
+///
+/// A Möbius transformation is represented by the matrix of its coefficients, since composing two
+/// transformations is the same as multiplying their matrices: `Prod<F, G>` is the transformation
+/// which applies `G` and then `F`. Likewise, the [`Inverse`] of a transformation undoes it. Use
+/// [`ApplyMobius`] to apply a transformation to a [`Ratio`].
+///
+/// # Example
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Mobius, Ratio, operator_aliases::*};
+///
+/// // x => 1 + 1/x, the step of the continued fraction of the golden ratio.
+/// type Step = Mobius<Ratio<P1>, Ratio<P1>, Ratio<P1>, Ratio<Z0>>;
+/// type ThreeSteps = Prod<Step, Prod<Step, Step>>;
+///
+/// assert_eq!(ApplyMobius::<ThreeSteps, Ratio<P1>>::default(), Ratio::<P5, P3>::default());
+/// assert_eq!(ApplyMobius::<Inverse<Step>, Ratio<P5, P3>>::default(), Ratio::<P3, P2>::default());
+/// ```
+///
+/// [`Inverse`]: ./operator_aliases/type.Inverse.html
+/// [`ApplyMobius`]: ./operator_aliases/type.ApplyMobius.html
+/// [`Ratio`]: ./struct.Ratio.html
+pub type Mobius<A, B, C, D> = RatMat2<A, B, C, D>;
+
+impl<A, B, C, D> Default for RatMat2<A, B, C, D> {
+    fn default() -> Self {
+        RatMat2(PhantomData)
+    }
+}
+
+impl<A1, B1, C1, D1, A2, B2, C2, D2> PartialEq<RatMat2<A2, B2, C2, D2>> for RatMat2<A1, B1, C1, D1>
+    where A1: PartialEq<A2> + Default,
+          B1: PartialEq<B2> + Default,
+          C1: PartialEq<C2> + Default,
+          D1: PartialEq<D2> + Default,
+          A2: Default,
+          B2: Default,
+          C2: Default,
+          D2: Default,
+{
+    fn eq(&self, _: &RatMat2<A2, B2, C2, D2>) -> bool {
+        A1::default() == A2::default()
+            && B1::default() == B2::default()
+            && C1::default() == C2::default()
+            && D1::default() == D2::default()
+    }
+}
+
+impl<A, B, C, D> fmt::Debug for RatMat2<A, B, C, D>
+    where A: fmt::Debug + Default,
+          B: fmt::Debug + Default,
+          C: fmt::Debug + Default,
+          D: fmt::Debug + Default,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[[{:?}, {:?}], [{:?}, {:?}]]", A::default(), B::default(), C::default(), D::default())
+    }
+}
+
+/// | A1 B1 | | A2 B2 |   | A1*A2 + B1*C2  A1*B2 + B1*D2 |
+/// | C1 D1 | | C2 D2 | = | C1*A2 + D1*C2  C1*B2 + D1*D2 |
+impl<A1, B1, C1, D1, A2, B2, C2, D2> Mul<RatMat2<A2, B2, C2, D2>> for RatMat2<A1, B1, C1, D1>
+    where A1: Mul<A2> + Mul<B2>,
+          B1: Mul<C2> + Mul<D2>,
+          C1: Mul<A2> + Mul<B2>,
+          D1: Mul<C2> + Mul<D2>,
+          Prod<A1, A2>: Add<Prod<B1, C2>>,
+          Prod<A1, B2>: Add<Prod<B1, D2>>,
+          Prod<C1, A2>: Add<Prod<D1, C2>>,
+          Prod<C1, B2>: Add<Prod<D1, D2>>,
+{
+    type Output = RatMat2<
+        Sum<Prod<A1, A2>, Prod<B1, C2>>,
+        Sum<Prod<A1, B2>, Prod<B1, D2>>,
+        Sum<Prod<C1, A2>, Prod<D1, C2>>,
+        Sum<Prod<C1, B2>, Prod<D1, D2>>,
+    >;
+
+    fn mul(self, _: RatMat2<A2, B2, C2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// det = A*D - B*C
+impl<A, B, C, D> MatrixDeterminant for RatMat2<A, B, C, D>
+    where A: Mul<D>,
+          B: Mul<C>,
+          Prod<A, D>: Sub<Prod<B, C>>,
+{
+    type Output = Diff<Prod<A, D>, Prod<B, C>>;
+}
+
+/// | A B |^-1           |  D -B |
+/// | C D |    = 1/det * | -C  A |
+///
+/// There is no implementation if the determinant is zero, since it has no reciprocal.
+impl<A, B, C, D> Inv for RatMat2<A, B, C, D>
+    where RatMat2<A, B, C, D>: MatrixDeterminant,
+          Determinant<RatMat2<A, B, C, D>>: Inv,
+          B: Neg,
+          C: Neg,
+          D: Mul<Inverse<Determinant<RatMat2<A, B, C, D>>>>,
+          Negate<B>: Mul<Inverse<Determinant<RatMat2<A, B, C, D>>>>,
+          Negate<C>: Mul<Inverse<Determinant<RatMat2<A, B, C, D>>>>,
+          A: Mul<Inverse<Determinant<RatMat2<A, B, C, D>>>>,
+{
+    type Output = RatMat2<
+        Prod<D, Inverse<Determinant<RatMat2<A, B, C, D>>>>,
+        Prod<Negate<B>, Inverse<Determinant<RatMat2<A, B, C, D>>>>,
+        Prod<Negate<C>, Inverse<Determinant<RatMat2<A, B, C, D>>>>,
+        Prod<A, Inverse<Determinant<RatMat2<A, B, C, D>>>>,
+    >;
+}
+
+/// (A*x + B)/(C*x + D)
+///
+/// There is no implementation if `C*x + D` is zero, i.e. at the pole of the transformation.
+impl<A, B, C, D, N, E> MobiusTransform<Ratio<N, E>> for RatMat2<A, B, C, D>
+    where A: Mul<Ratio<N, E>>,
+          C: Mul<Ratio<N, E>>,
+          Prod<A, Ratio<N, E>>: Add<B>,
+          Prod<C, Ratio<N, E>>: Add<D>,
+          Sum<Prod<C, Ratio<N, E>>, D>: Inv,
+          Sum<Prod<A, Ratio<N, E>>, B>: Mul<Inverse<Sum<Prod<C, Ratio<N, E>>, D>>>,
+{
+    type Output = Prod<Sum<Prod<A, Ratio<N, E>>, B>, Inverse<Sum<Prod<C, Ratio<N, E>>, D>>>;
+}
@@ -1,6 +1,11 @@
 //! Convenient aliases for operations on rational numbers.
 
-use super::{Ratio, Rational};
+use typenum::{N1, P1, P2, P10, Z0};
+use typenum::operator_aliases::{AbsVal, Diff, Exp, Le, Length, Negate, Prod, Quot, Sum};
+use typenum::type_operators::IsEqual;
+
+use super::{RatInterval, Ratio, RawRatio, Rational, ToRatio, ToURatio};
+use super::type_operators::*;
 
 /// Reduces `N/D` and extracts the numerator.
 ///
@@ -34,4 +39,1613 @@ pub type Num<N, D> = <Ratio<N, D> as Rational>::Num;
 /// ```
 pub type Den<N, D> = <Ratio<N, D> as Rational>::Den;
 
-pub(crate) type ReducedRatio<N, D> = Ratio<Num<N, D>, Den<N, D>>;
+/// The reduced form of `N/D`, the canonical `Ratio` equal to it.
+///
+/// This is useful as the `Output` of an operation generic over `Ratio<N, D>`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{consts::*, type_operators::Same};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// same::<Reduce<P2, P4>, Ratio<P1, P2>>();
+/// same::<Reduce<P3, N6>, Ratio<N1, P2>>();
+/// ```
+pub type Reduce<N, D> = Ratio<Num<N, D>, Den<N, D>>;
+
+/// Promotes a [`Rational`] or unsigned integer to its canonical [`Ratio`].
+///
+/// [`Rational`]: ../trait.Rational.html
+///
+/// [`Ratio`]: ../struct.Ratio.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(AsRatio::<U4>::default(), Ratio::<P4>::default());
+/// assert_eq!(AsRatio::<N2>::default(), Ratio::<N4, P2>::default());
+/// ```
+pub type AsRatio<T> = <T as ToRatio>::Output;
+
+/// Converts a non-negative [`Rational`] to its canonical [`URatio`].
+///
+/// [`Rational`]: ../trait.Rational.html
+/// [`URatio`]: ../struct.URatio.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, URatio, operator_aliases::*};
+///
+/// assert_eq!(AsURatio::<Ratio<P4, P6>>::default(), URatio::<U2, U3>::default());
+/// ```
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// let _ = AsURatio::<Ratio<N1, P2>>::default();
+/// ```
+pub type AsURatio<R> = <R as ToURatio>::Output;
+
+/// Converts a [`Rational`] to a [`RawRatio`], whose operators skip reduction.
+///
+/// [`Rational`]: ../trait.Rational.html
+/// [`RawRatio`]: ../struct.RawRatio.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{consts::*, type_operators::Same};
+/// use typenum_ratio::{Ratio, RawRatio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// same::<AsRawRatio<Ratio<P2, P4>>, RawRatio<P1, P2>>();
+/// same::<Sum<AsRawRatio<P1>, AsRawRatio<Ratio<P1, P2>>>, RawRatio<P3, P2>>();
+/// ```
+pub type AsRawRatio<R> = RawRatio<<R as Rational>::Num, <R as Rational>::Den>;
+
+/// The sum of two [`Rational`]s, as a reduced `Ratio`.
+///
+/// Unlike [`Sum`], the operands may be any types which implement [`Rational`].
+///
+/// [`Rational`]: ../trait.Rational.html
+/// [`Sum`]: https://docs.rs/typenum/1.10.0/typenum/operator_aliases/type.Sum.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, Rational, operator_aliases::*};
+///
+/// struct Half;
+///
+/// impl Rational for Half {
+///     type Num = P1;
+///     type Den = P2;
+/// }
+///
+/// assert_eq!(RatSum::<Half, Ratio<P1, P3>>::default(), Ratio::<P5, P6>::default());
+/// ```
+pub type RatSum<A, B> = Sum<AsRatio<A>, AsRatio<B>>;
+
+/// The difference of two [`Rational`]s, as a reduced `Ratio`.
+///
+/// [`Rational`]: ../trait.Rational.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(RatDiff::<Ratio<P1, P2>, Ratio<P1, P3>>::default(), Ratio::<P1, P6>::default());
+/// ```
+pub type RatDiff<A, B> = Diff<AsRatio<A>, AsRatio<B>>;
+
+/// The product of two [`Rational`]s, as a reduced `Ratio`.
+///
+/// [`Rational`]: ../trait.Rational.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(RatProd::<Ratio<P2, P3>, Ratio<P3, P4>>::default(), Ratio::<P1, P2>::default());
+/// ```
+pub type RatProd<A, B> = Prod<AsRatio<A>, AsRatio<B>>;
+
+/// The quotient of two [`Rational`]s, as a reduced `Ratio`.
+///
+/// [`Rational`]: ../trait.Rational.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(RatQuot::<Ratio<P2, P3>, Ratio<P4, P9>>::default(), Ratio::<P3, P2>::default());
+/// ```
+pub type RatQuot<A, B> = Quot<AsRatio<A>, AsRatio<B>>;
+
+/// The least common multiple of `A` and `B`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // Clocks ticking every 3/4 and 5/6 of a second coincide every 15/2 seconds.
+/// assert_eq!(Lcmf::<Ratio<P3, P4>, Ratio<P5, P6>>::default(), Ratio::<P15, P2>::default());
+/// assert_eq!(Lcmf::<Ratio<N1, P3>, P2>::default(), Ratio::<P2>::default());
+/// assert_eq!(Lcmf::<U4, Ratio<P3, P2>>::default(), Ratio::<P12>::default());
+/// ```
+pub type Lcmf<A, B> = <A as Lcm<B>>::Output;
+
+/// The least common denominator of the [`Rational`]s `A` and `B`, as a [`typenum::Integer`].
+///
+/// This is the least common multiple of their reduced denominators. Adding many fractions is much
+/// cheaper for the trait solver when their numerators are first re-expressed over a common
+/// denominator with [`NumOverLcd`] and summed as integers.
+///
+/// [`Rational`]: ../trait.Rational.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+/// [`NumOverLcd`]: ./type.NumOverLcd.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{consts::*, type_operators::Same};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// same::<Lcd<Ratio<P1, P4>, Ratio<P5, P6>>, P12>();
+/// same::<Lcd<Ratio<P2, P4>, P3>, P2>();
+/// ```
+pub type Lcd<A, B> = Prod<
+    <A as Rational>::Den,
+    Quot<<B as Rational>::Den, Gcf<<A as Rational>::Den, <B as Rational>::Den>>,
+>;
+
+/// The numerator of `A` when it is written over [`Lcd<A, B>`].
+///
+/// The numerator of `B` over the same denominator is `NumOverLcd<B, A>`.
+///
+/// [`Lcd<A, B>`]: ./type.Lcd.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{consts::*, type_operators::Same};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// type A = Ratio<P1, P4>;
+/// type B = Ratio<N5, P6>;
+///
+/// // 1/4 - 5/6 = 3/12 - 10/12 = -7/12
+/// same::<NumOverLcd<A, B>, P3>();
+/// same::<NumOverLcd<B, A>, N10>();
+/// same::<Reduce<Sum<NumOverLcd<A, B>, NumOverLcd<B, A>>, Lcd<A, B>>, Ratio<N7, P12>>();
+/// ```
+pub type NumOverLcd<A, B> = Prod<<A as Rational>::Num, Quot<Lcd<A, B>, <A as Rational>::Den>>;
+
+/// `R` raised to the rational power `E`, which only compiles if the result is rational.
+///
+/// The root is found by searching upward from one, so roots of very large integers may require a
+/// higher recursion limit.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(PowRatio::<Ratio<P4, P9>, Ratio<P1, P2>>::default(), Ratio::<P2, P3>::default());
+/// assert_eq!(PowRatio::<Ratio<P8>, Ratio<N2, P3>>::default(), Ratio::<P1, P4>::default());
+/// ```
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // The square root of 2 is irrational.
+/// let _ = PowRatio::<Ratio<P2>, Ratio<P1, P2>>::default();
+/// ```
+pub type PowRatio<R, E> = <R as RationalPow<E>>::Output;
+
+/// The sign of `R`: `N1`, `Z0` or `P1`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(Signum::<Ratio<P3, N4>>::to_i32(), -1);
+/// assert_eq!(Signum::<Ratio<Z0>>::to_i32(), 0);
+/// ```
+pub type Signum<R> = <R as RatioSignum>::Output;
+
+/// Whether `R` is greater than zero, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsPositive::<Ratio<N3, N4>>::to_bool());
+/// assert!(!IsPositive::<Ratio<Z0>>::to_bool());
+/// ```
+pub type IsPositive<R> = <Signum<R> as IsEqual<P1>>::Output;
+
+/// Whether `R` is less than zero, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsNegative::<Ratio<P3, N4>>::to_bool());
+/// assert!(!IsNegative::<Ratio<Z0>>::to_bool());
+/// ```
+pub type IsNegative<R> = <Signum<R> as IsEqual<N1>>::Output;
+
+/// Whether `R` is zero, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsZero::<Ratio<Z0, P7>>::to_bool());
+/// assert!(!IsZero::<Ratio<P1, P7>>::to_bool());
+/// ```
+pub type IsZero<R> = <Signum<R> as IsEqual<Z0>>::Output;
+
+/// Whether `R` is an integer, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsInteger::<Ratio<P6, P3>>::to_bool());
+/// assert!(!IsInteger::<Ratio<P3, P6>>::to_bool());
+/// ```
+pub type IsInteger<R> = <R as IntegralRatio>::Output;
+
+/// The [`typenum::Integer`] equal to `R`, which must be a whole number.
+///
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{consts::*, type_operators::Same};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// same::<ToInteger<Ratio<P12, N4>>, N3>();
+/// same::<ToInteger<Prod<Ratio<P3, P4>, Ratio<P8>>>, P6>();
+/// ```
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// let _ = ToInteger::<Ratio<P3, P2>>::default();
+/// ```
+pub type ToInteger<R> = <R as WholeRatio>::Output;
+
+/// Whether `A` divides `B`, i.e. whether `B/A` is an integer, as a [`typenum::Bit`].
+///
+/// `A` must not be zero.
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, consts::*, operator_aliases::*};
+///
+/// assert!(Divides::<Milli, Kilo>::to_bool());
+/// assert!(Divides::<Ratio<P1, P4>, Ratio<P3, P2>>::to_bool());
+/// assert!(!Divides::<Ratio<P2, P3>, Ratio<P1>>::to_bool());
+/// ```
+pub type Divides<A, B> = IsInteger<Prod<B, Inverse<A>>>;
+
+/// Whether `R` is a proper fraction, i.e. whether `|R| < 1`, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsProperFraction::<Ratio<N2, P3>>::to_bool());
+/// assert!(!IsProperFraction::<Ratio<P3, P3>>::to_bool());
+/// ```
+pub type IsProperFraction<R> = Le<AbsVal<R>, Ratio<P1>>;
+
+/// Whether the reduced numerator of `R` is one, as a [`typenum::Bit`].
+///
+/// The unit fractions are `1/1, 1/2, 1/3, ...`.
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsUnitFraction::<Ratio<P3, P21>>::to_bool());
+/// assert!(!IsUnitFraction::<Ratio<P2, P21>>::to_bool());
+/// assert!(!IsUnitFraction::<Ratio<N1, P7>>::to_bool());
+/// ```
+pub type IsUnitFraction<R> = <<R as Rational>::Num as IsEqual<P1>>::Output;
+
+/// Whether the reduced denominator of `R` is a power of two, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsDyadic::<Ratio<P3, P8>>::to_bool());
+/// assert!(!IsDyadic::<Ratio<P1, P10>>::to_bool());
+/// ```
+pub type IsDyadic<R> = <R as DyadicRatio>::Output;
+
+/// Whether `R` has a terminating decimal expansion, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsTerminatingDecimal::<Ratio<P7, P40>>::to_bool());
+/// assert!(!IsTerminatingDecimal::<Ratio<P1, P6>>::to_bool());
+/// ```
+pub type IsTerminatingDecimal<R> = <R as TerminatingDecimal>::Output;
+
+/// Whether `R` is the square of a rational number, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsPerfectSquare::<Ratio<P9, P16>>::to_bool());
+/// assert!(!IsPerfectSquare::<Ratio<P8, P9>>::to_bool());
+/// assert!(!IsPerfectSquare::<Ratio<N1, P4>>::to_bool());
+/// ```
+pub type IsPerfectSquare<R> = <R as PerfectSquare>::Output;
+
+/// `R` clamped to the closed interval `[Lo, Hi]`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// type Lo = Ratio<P1, P4>;
+/// type Hi = Ratio<P4>;
+///
+/// assert_eq!(Clamp::<Ratio<P1, P8>, Lo, Hi>::default(), Lo::default());
+/// assert_eq!(Clamp::<Ratio<P3, P2>, Lo, Hi>::default(), Ratio::<P3, P2>::default());
+/// assert_eq!(Clamp::<Ratio<P9, P2>, Lo, Hi>::default(), Hi::default());
+/// ```
+///
+/// The bounds must be in order:
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// let _ = Clamp::<Ratio<P1>, Ratio<P2>, Ratio<P1, P2>>::default();
+/// ```
+pub type Clamp<R, Lo, Hi> = <R as ClampTo<Lo, Hi>>::Output;
+
+/// The reciprocal of `R`, `1/R`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(Inverse::<Ratio<P3, P4>>::default(), Ratio::<P4, P3>::default());
+/// assert_eq!(Inverse::<Ratio<N2>>::default(), Ratio::<N1, P2>::default());
+/// ```
+///
+/// Zero has no reciprocal:
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// let _ = Inverse::<Ratio<Z0>>::default();
+/// ```
+pub type Inverse<R> = <R as Inv>::Output;
+
+/// The root of the linear equation `A*x + B = 0`, which is `-B/A`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // 3x - 2 = 0
+/// assert_eq!(SolveLinear::<Ratio<P3>, Ratio<N2>>::default(), Ratio::<P2, P3>::default());
+/// ```
+///
+/// The equation has no unique root if `A` is zero:
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// let _ = SolveLinear::<Ratio<Z0>, Ratio<P1>>::default();
+/// ```
+pub type SolveLinear<A, B> = Negate<Prod<B, Inverse<A>>>;
+
+/// The determinant of the matrix `M`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, RatMat2, operator_aliases::*};
+///
+/// type M = RatMat2<Ratio<P1, P2>, Ratio<P1, P3>, Ratio<P3>, Ratio<P4>>;
+/// assert_eq!(Determinant::<M>::default(), Ratio::<P1>::default());
+/// ```
+pub type Determinant<M> = <M as MatrixDeterminant>::Output;
+
+/// The result of applying the Möbius transformation `M` to `X`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Mobius, Ratio, operator_aliases::*};
+///
+/// // x => (2x + 1)/(x + 3)
+/// type M = Mobius<Ratio<P2>, Ratio<P1>, Ratio<P1>, Ratio<P3>>;
+/// assert_eq!(ApplyMobius::<M, Ratio<P1, P2>>::default(), Ratio::<P4, P7>::default());
+/// ```
+pub type ApplyMobius<M, X> = <M as MobiusTransform<X>>::Output;
+
+/// The intersection of the intervals `A` and `B`, which must overlap.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, RatInterval, operator_aliases::*};
+///
+/// type A = RatInterval<Ratio<Z0>, Ratio<P1, P2>>;
+/// type B = RatInterval<Ratio<P1, P3>, Ratio<P1>>;
+/// assert_eq!(Intersection::<A, B>::default(),
+///            RatInterval::<Ratio<P1, P3>, Ratio<P1, P2>>::default());
+/// ```
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, RatInterval, operator_aliases::*};
+///
+/// // The intervals are disjoint.
+/// let _ = Intersection::<RatInterval<Ratio<Z0>, Ratio<P1>>, RatInterval<Ratio<P2>, Ratio<P3>>>::default();
+/// ```
+pub type Intersection<A, B> = <A as IntervalIntersection<B>>::Output;
+
+/// Whether the interval `I` contains `R`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, RatInterval, operator_aliases::*};
+///
+/// type Unit = RatInterval<Ratio<Z0>, Ratio<P1>>;
+/// assert!(Contains::<Unit, Ratio<P1>>::to_bool());
+/// assert!(!Contains::<Unit, Ratio<P4, P3>>::to_bool());
+/// ```
+pub type Contains<I, R> = <I as IntervalContains<R>>::Output;
+
+/// Whether `R` lies in the closed interval `[Lo, Hi]`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{True, consts::*, type_operators::Same};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// /// Mixes two signals, keeping `Gain` of the first.
+/// fn mix<Gain>(a: f64, b: f64) -> f64
+///     where InBounds<Gain, Ratio<Z0>, Ratio<P1>>: Same<True>,
+///           Gain: Default,
+///           f64: std::ops::Mul<Gain, Output = f64>,
+/// {
+///     a * Gain::default() + b - b * Gain::default()
+/// }
+///
+/// assert_eq!(mix::<Ratio<P3, P4>>(4.0, 8.0), 5.0);
+/// ```
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_ratio;
+/// # use typenum::{True, consts::*, type_operators::Same};
+/// # use typenum_ratio::{Ratio, operator_aliases::*};
+/// fn mix<Gain>() where InBounds<Gain, Ratio<Z0>, Ratio<P1>>: Same<True> {}
+///
+/// mix::<Ratio<P5, P4>>();
+/// ```
+pub type InBounds<R, Lo, Hi> = Contains<RatInterval<Lo, Hi>, R>;
+
+/// The parallel combination of `A` and `B`, `1/(1/A + 1/B)`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // A 2Ω and 3Ω resistor in parallel.
+/// assert_eq!(Parallel::<Ratio<P2>, Ratio<P3>>::default(), Ratio::<P6, P5>::default());
+/// ```
+pub type Parallel<A, B> = <A as CombineParallel<B>>::Output;
+
+/// The transfer ratio of a voltage divider with output measured across `A`, `A/(A + B)`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(Divider::<Ratio<P10>, Ratio<P30>>::default(), Ratio::<P1, P4>::default());
+/// ```
+pub type Divider<A, B> = <A as DividerRatio<B>>::Output;
+
+/// The component `B` such that `Divider<A, B>` equals `Target`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(DividerComplement::<Ratio<P10>, Ratio<P1, P4>>::default(), Ratio::<P30>::default());
+/// ```
+///
+/// The exact value is rarely available as a real component. See [`NearestDividerComplement`] to
+/// choose one from a list of preferred values.
+///
+/// [`NearestDividerComplement`]: ./type.NearestDividerComplement.html
+pub type DividerComplement<A, Target> = <A as DividerSolve<Target>>::Output;
+
+/// The component in the preferred-value list `L` which is nearest to
+/// `DividerComplement<A, Target>`.
+///
+/// Use [`Divider`] to find the transfer ratio which the chosen component actually gives.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // The E6 series, in kΩ.
+/// type E6 = tarr![Ratio<P10>, Ratio<P15>, Ratio<P22>, Ratio<P33>, Ratio<P47>, Ratio<P68>];
+///
+/// // Divide by three with a 10kΩ resistor on the output side. The exact complement is 20kΩ.
+/// type B = NearestDividerComplement<Ratio<P10>, Ratio<P1, P3>, E6>;
+/// assert_eq!(B::default(), Ratio::<P22>::default());
+/// assert_eq!(Divider::<Ratio<P10>, B>::default(), Ratio::<P5, P16>::default());
+/// ```
+///
+/// [`Divider`]: ./type.Divider.html
+pub type NearestDividerComplement<A, Target, L> = NearestOf<L, DividerComplement<A, Target>>;
+
+/// The midpoint of `A` and `B`, `(A + B)/2`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(Midpoint::<Ratio<P1, P3>, Ratio<P1, P2>>::default(), Ratio::<P5, P12>::default());
+/// assert_eq!(Midpoint::<Ratio<N1>, Ratio<P1>>::default(), Ratio::<Z0>::default());
+/// ```
+pub type Midpoint<A, B> = Quot<Sum<A, B>, P2>;
+
+/// The geometric mean of `A` and `B`, `(A*B)^(1/2)`.
+///
+/// This is only implemented when the result is rational, i.e. when `A*B` is a perfect square (see
+/// [`IsPerfectSquare`]). In particular, `A` and `B` must not have opposite signs.
+///
+/// [`IsPerfectSquare`]: ./type.IsPerfectSquare.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(GeometricMean::<Ratio<P1, P2>, Ratio<P8>>::default(), Ratio::<P2>::default());
+/// assert_eq!(GeometricMean::<Ratio<P4, P3>, Ratio<P1, P3>>::default(), Ratio::<P2, P3>::default());
+/// ```
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // 1*2 is not a perfect square.
+/// let _ = GeometricMean::<Ratio<P1>, Ratio<P2>>::default();
+/// ```
+pub type GeometricMean<A, B> = PowRatio<Prod<A, B>, Ratio<P1, P2>>;
+
+/// The absolute difference of `A` and `B`, `|A - B|`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*, operator_aliases::LeEq};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(AbsDiff::<Ratio<P1, P3>, Ratio<P1, P2>>::default(), Ratio::<P1, P6>::default());
+///
+/// // 22/7 is within 1/100 of 3.14
+/// assert!(LeEq::<AbsDiff<Ratio<P22, P7>, Ratio<P314, P100>>, Ratio<P1, P100>>::to_bool());
+/// ```
+pub type AbsDiff<A, B> = AbsVal<Diff<A, B>>;
+
+/// The growth factor `(1 + R)^N` obtained by compounding a rate `R` over `N` periods.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // 10% growth over two periods.
+/// assert_eq!(CompoundFactor::<Ratio<P1, P10>, P2>::default(), Ratio::<P121, P100>::default());
+///
+/// // 50% decay over three periods.
+/// assert_eq!(CompoundFactor::<Ratio<N1, P2>, U3>::default(), Ratio::<P1, P8>::default());
+/// ```
+pub type CompoundFactor<R, N> = Exp<Sum<Ratio<P1>, R>, N>;
+
+/// The sum of the geometric series `1 + R + R^2 + ... + R^N`, `(1 - R^(N+1))/(1 - R)`.
+///
+/// `R` must not be equal to `1`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // 1 + 1/2 + 1/4 + 1/8
+/// assert_eq!(GeometricSum::<Ratio<P1, P2>, U3>::default(), Ratio::<P15, P8>::default());
+/// ```
+pub type GeometricSum<R, N> =
+    Quot<
+        Diff<Ratio<P1>, Prod<Exp<R, N>, R>>,
+        Diff<Ratio<P1>, R>
+    >;
+
+/// The list `tarr![1/0!, 1/1!, ..., 1/N!]` of Taylor coefficients of `exp(x)`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Same, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// same::<InvFactorials<U3>, tarr![Ratio<P1>, Ratio<P1>, Ratio<P1, P2>, Ratio<P1, P6>]>();
+/// ```
+pub type InvFactorials<N> = <N as TaylorCoefficients>::Output;
+
+/// The `N`-th convergent of the golden ratio, `F(N + 1)/F(N)`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(GoldenConvergent::<U5>::default(), Ratio::<P8, P5>::default());
+/// ```
+pub type GoldenConvergent<N> = <N as FibonacciRatio>::Output;
+
+/// The coefficients of the simple continued fraction of `R`, as a type-level list of integers.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Same, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// // 415/93 = 4 + 1/(2 + 1/(6 + 1/7))
+/// same::<ContinuedFraction<Ratio<P415, P93>>, tarr![P4, P2, P6, P7]>();
+/// ```
+pub type ContinuedFraction<R> = <R as ContinuedFractionExpansion>::Output;
+
+/// The value of the simple continued fraction with the coefficients in the type-level list `Terms`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(EvalCFrac::<tarr![P3, P7, P16]>::default(), Ratio::<P355, P113>::default());
+/// assert_eq!(EvalCFrac::<ContinuedFraction<Ratio<P415, P93>>>::default(),
+///            Ratio::<P415, P93>::default());
+/// ```
+pub type EvalCFrac<Terms> = <Terms as ContinuedFractionValue>::Output;
+
+/// The path to the positive ratio `R` in the Stern–Brocot tree, as a type-level list of bits where
+/// `B0` is left and `B1` is right.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Same, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// same::<SternBrocotPath<Ratio<P3, P5>>, tarr![B0, B1, B0]>();
+/// same::<SternBrocotPath<Ratio<P1>>, tarr![]>();
+/// ```
+pub type SternBrocotPath<R> = <R as SternBrocotEncode>::Output;
+
+/// The ratio at the end of `Path` in the Stern–Brocot tree.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(FromSternBrocot::<tarr![B0, B1, B0]>::default(), Ratio::<P3, P5>::default());
+/// assert_eq!(FromSternBrocot::<SternBrocotPath<Ratio<P22, P7>>>::default(),
+///            Ratio::<P22, P7>::default());
+/// ```
+pub type FromSternBrocot<Path> = <Path as SternBrocotDecode>::Output;
+
+/// The `U`-th positive rational in the Calkin–Wilf sequence, counting from zero.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(NthRational::<U0>::default(), Ratio::<P1>::default());
+/// assert_eq!(NthRational::<U4>::default(), Ratio::<P3, P2>::default());
+/// assert_eq!(NthRational::<U5>::default(), Ratio::<P2, P3>::default());
+/// ```
+pub type NthRational<U> = <U as CalkinWilf>::Output;
+
+/// The type-level list `tarr![Start, Start + Step, ..., Start + (N - 1)*Step]`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Same, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// same::<ArithSeq<Ratio<Z0>, Ratio<P1, P2>, U3>, tarr![Ratio<Z0>, Ratio<P1, P2>, Ratio<P1>]>();
+/// ```
+pub type ArithSeq<Start, Step, N> = <Start as ArithmeticSequence<Step, N>>::Output;
+
+/// The type-level list `tarr![Start, Start * Factor, ..., Start * Factor^(N - 1)]`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Same, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// fn same<A: Same<B>, B>() {}
+///
+/// same::<GeomSeq<Ratio<P1>, Ratio<P1, P2>, U3>, tarr![Ratio<P1>, Ratio<P1, P2>, Ratio<P1, P4>]>();
+/// ```
+pub type GeomSeq<Start, Factor, N> = <Start as GeometricSequence<Factor, N>>::Output;
+
+/// The sum of every ratio in the type-level list `L`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // The taps of a normalized smoothing filter sum to one.
+/// assert_eq!(SumAll::<tarr![Ratio<P1, P4>, Ratio<P1, P2>, Ratio<P1, P4>]>::default(),
+///            Ratio::<P1>::default());
+/// ```
+pub type SumAll<L> = <L as SumList>::Output;
+
+/// The product of every ratio in the type-level list `L`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // A 3:1 reduction followed by a 20:1 reduction and a 4:5 step-up.
+/// assert_eq!(ProductAll::<tarr![Ratio<P1, P3>, Ratio<P1, P20>, Ratio<P5, P4>]>::default(),
+///            Ratio::<P1, P48>::default());
+/// ```
+pub type ProductAll<L> = <L as ProductList>::Output;
+
+/// The arithmetic mean of the non-empty type-level list of ratios `L`, reduced.
+///
+/// The mean of an empty list would divide by zero, so `MeanOf<ATerm>` fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(MeanOf::<tarr![Ratio<P1, P2>, Ratio<P1, P3>, Ratio<P1, P6>]>::default(),
+///            Ratio::<P1, P3>::default());
+/// ```
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_ratio;
+/// # use typenum::ATerm;
+/// # use typenum_ratio::operator_aliases::*;
+/// let _ = MeanOf::<ATerm>::default();
+/// ```
+pub type MeanOf<L> = Quot<SumAll<L>, Length<L>>;
+
+/// The dot product of the equal-length type-level lists of ratios `A` and `B`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // The response of a 3-tap filter to the samples 4, -2, 1.
+/// type Taps = tarr![Ratio<P1, P4>, Ratio<P1, P2>, Ratio<P1, P4>];
+/// type Samples = tarr![Ratio<P4>, Ratio<N2>, Ratio<P1>];
+/// assert_eq!(DotProduct::<Taps, Samples>::default(), Ratio::<P1, P4>::default());
+/// ```
+pub type DotProduct<A, B> = <A as InnerProduct<B>>::Output;
+
+/// The mean of the type-level list of ratios `Values`, weighted by the equal-length list of ratios
+/// `Weights`, reduced.
+///
+/// The weights need not sum to one, but they must not sum to zero.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// type Values = tarr![Ratio<P1>, Ratio<P2>, Ratio<P4>];
+/// type Weights = tarr![Ratio<P3>, Ratio<P2>, Ratio<P1>];
+/// assert_eq!(WeightedMean::<Values, Weights>::default(), Ratio::<P11, P6>::default());
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate typenum;
+/// # extern crate typenum_ratio;
+/// # use typenum::consts::*;
+/// # use typenum_ratio::{Ratio, operator_aliases::*};
+/// type Values = tarr![Ratio<P1>, Ratio<P2>];
+/// type Weights = tarr![Ratio<P1>, Ratio<N1>];
+/// let _ = WeightedMean::<Values, Weights>::default();
+/// ```
+pub type WeightedMean<Values, Weights> = Quot<DotProduct<Values, Weights>, SumAll<Weights>>;
+
+/// The type-level list of ratios `L`, sorted in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*, to_pairs};
+///
+/// type Rates = tarr![Ratio<P3, P2>, Ratio<N1, P2>, Ratio<P2, P3>];
+/// assert_eq!(to_pairs::<Sorted<Rates>, 3>(), [(-1, 2), (2, 3), (3, 2)]);
+/// ```
+pub type Sorted<L> = <L as SortList>::Output;
+
+/// The least ratio in the non-empty type-level list `L`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// type Rates = tarr![Ratio<P3, P2>, Ratio<P1, P2>, Ratio<P2>];
+/// assert_eq!(MinOf::<Rates>::default(), Ratio::<P1, P2>::default());
+/// ```
+pub type MinOf<L> = <L as MinimumList>::Output;
+
+/// The greatest ratio in the non-empty type-level list `L`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// type Rates = tarr![Ratio<P3, P2>, Ratio<P1, P2>, Ratio<P2>];
+/// assert_eq!(MaxOf::<Rates>::default(), Ratio::<P2>::default());
+/// ```
+pub type MaxOf<L> = <L as MaximumList>::Output;
+
+/// The ratio in the non-empty type-level list `L` which is nearest to `X`.
+///
+/// If two elements are equally near to `X`, the first of them is chosen.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// type Steps = tarr![Ratio<P1, P4>, Ratio<P1, P2>, Ratio<P1>];
+/// assert_eq!(NearestOf::<Steps, Ratio<P2, P5>>::default(), Ratio::<P1, P2>::default());
+/// ```
+pub type NearestOf<L, X> = <L as NearestInList<X>>::Output;
+
+/// The floor of the logarithm of `R` in base `B`, as a [`typenum::Integer`].
+///
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(LogFloor::<Ratio<P100>, P10>::to_i32(), 2);
+/// assert_eq!(LogFloor::<Ratio<P1, P60>, P60>::to_i32(), -1);
+/// assert_eq!(LogFloor::<Ratio<P3, P4>, P2>::to_i32(), -1);
+/// ```
+pub type LogFloor<R, B> = <R as IntegerLog<B>>::Output;
+
+/// The greatest integer `k` such that `2^k <= R`, as a [`typenum::Integer`].
+///
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(FloorLog2::<Ratio<P1000>>::to_i32(), 9);
+/// assert_eq!(FloorLog2::<Ratio<P1, P3>>::to_i32(), -2);
+/// ```
+pub type FloorLog2<R> = LogFloor<R, P2>;
+
+/// The greatest integer `k` such that `10^k <= R`, as a [`typenum::Integer`].
+///
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(FloorLog10::<Ratio<P512>>::to_i32(), 2);
+///
+/// // 0.33 uF is displayed as 330 nF.
+/// assert_eq!(FloorLog10::<Ratio<P33, P100000000>>::to_i32(), -7);
+/// ```
+pub type FloorLog10<R> = LogFloor<R, P10>;
+
+/// Whether `R` is `10^k` for some integer `k`, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, consts::*, operator_aliases::*};
+///
+/// assert!(IsPowerOfTen::<Kilo>::to_bool());
+/// assert!(IsPowerOfTen::<Milli>::to_bool());
+/// assert!(!IsPowerOfTen::<Ratio<P20>>::to_bool());
+/// ```
+pub type IsPowerOfTen<R> = <R as PowerOfBase<P10>>::Output;
+
+/// The integer `k` such that `10^k == R`, as a [`typenum::Integer`].
+///
+/// This is only defined if `R` is a power of ten; see [`IsPowerOfTen`].
+///
+/// [`IsPowerOfTen`]: ./type.IsPowerOfTen.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{consts::*, operator_aliases::*};
+///
+/// assert_eq!(Log10Exact::<Kilo>::to_i32(), 3);
+/// assert_eq!(Log10Exact::<Micro>::to_i32(), -6);
+/// ```
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// let _ = Log10Exact::<Ratio<P50>>::to_i32();
+/// ```
+pub type Log10Exact<R> = <R as ExactLog<P10>>::Output;
+
+/// Whether `R` is `2^k` for some integer `k`, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsPowerOfTwo::<Ratio<P1024>>::to_bool());
+/// assert!(IsPowerOfTwo::<Ratio<P1, P8>>::to_bool());
+/// assert!(!IsPowerOfTwo::<Ratio<P1000>>::to_bool());
+/// ```
+pub type IsPowerOfTwo<R> = <R as PowerOfBase<P2>>::Output;
+
+/// The integer `k` such that `2^k == R`, as a [`typenum::Integer`].
+///
+/// This is only defined if `R` is a power of two; see [`IsPowerOfTwo`].
+///
+/// [`IsPowerOfTwo`]: ./type.IsPowerOfTwo.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // A Q16 scale factor is a shift by 16 bits.
+/// assert_eq!(Log2Exact::<Ratio<P1, P65536>>::to_i32(), -16);
+/// ```
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// let _ = Log2Exact::<Ratio<P3, P4>>::to_i32();
+/// ```
+pub type Log2Exact<R> = <R as ExactLog<P2>>::Output;
+
+/// The smallest integer `k` such that `2^k >= R`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(NextPowerOfTwoExponent::<Ratio<P5>>::to_i32(), 3);
+/// assert_eq!(NextPowerOfTwoExponent::<Ratio<P1, P4>>::to_i32(), -2);
+/// ```
+pub type NextPowerOfTwoExponent<R> = <R as CeilLog2>::Output;
+
+/// The smallest power of two, `2^k` for some integer `k`, which is not less than `R`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(NextPowerOfTwo::<Ratio<P5>>::default(), Ratio::<P8>::default());
+/// assert_eq!(NextPowerOfTwo::<Ratio<P1, P3>>::default(), Ratio::<P1, P2>::default());
+/// ```
+pub type NextPowerOfTwo<R> = Exp<Ratio<P2>, NextPowerOfTwoExponent<R>>;
+
+/// The greatest integer less than or equal to `R`, as a [`typenum::Integer`].
+///
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(Floor::<Ratio<P7, P2>>::to_i32(), 3);
+/// assert_eq!(Floor::<Ratio<N7, P2>>::to_i32(), -4);
+/// ```
+pub type Floor<R> = <R as IntegerFloor>::Output;
+
+/// The least integer greater than or equal to `R`, as a [`typenum::Integer`].
+///
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(Ceil::<Ratio<P7, P2>>::to_i32(), 4);
+/// assert_eq!(Ceil::<Ratio<N7, P2>>::to_i32(), -3);
+/// ```
+pub type Ceil<R> = <R as IntegerCeil>::Output;
+
+/// `R` rounded to an integer according to `Mode`, as a [`typenum::Integer`].
+///
+/// `Mode` is one of the rounding modes in the [`rounding`] module.
+///
+/// [`rounding`]: ../rounding/index.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*, rounding::*};
+///
+/// assert_eq!(Round::<Ratio<P5, P2>, HalfUp>::to_i32(), 3);
+/// assert_eq!(Round::<Ratio<P5, P2>, HalfEven>::to_i32(), 2);
+/// assert_eq!(Round::<Ratio<N7, P3>, TowardZero>::to_i32(), -2);
+/// assert_eq!(Round::<Ratio<N7, P3>, AwayFromZero>::to_i32(), -3);
+/// ```
+pub type Round<R, Mode> = <R as IntegerRound<Mode>>::Output;
+
+/// `R` rounded to an integer multiple of `Step` according to `Mode`.
+///
+/// `Mode` is one of the rounding modes in the [`rounding`] module.
+///
+/// [`rounding`]: ../rounding/index.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*, rounding::*};
+///
+/// type Quarter = Ratio<P1, P4>;
+///
+/// assert_eq!(RoundToMultiple::<Ratio<P7, P10>, Quarter, HalfUp>::default(),
+///            Ratio::<P3, P4>::default());
+/// assert_eq!(RoundToMultiple::<Ratio<P7, P10>, Quarter, TowardZero>::default(),
+///            Ratio::<P1, P2>::default());
+/// ```
+pub type RoundToMultiple<R, Step, Mode> = <R as NearestMultiple<Step, Mode>>::Output;
+
+/// The integer part of `R`, rounded toward zero, as a [`typenum::Integer`].
+///
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(Trunc::<Ratio<P7, P2>>::to_i32(), 3);
+/// assert_eq!(Trunc::<Ratio<N7, P2>>::to_i32(), -3);
+/// ```
+pub type Trunc<R> = <R as IntegerTrunc>::Output;
+
+/// The fractional part of `R`, such that `Trunc<R> + Fract<R> == R`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(Fract::<Ratio<P7, P2>>::default(), Ratio::<P1, P2>::default());
+/// assert_eq!(Fract::<Ratio<N7, P2>>::default(), Ratio::<N1, P2>::default());
+/// ```
+pub type Fract<R> = <R as FractionalPart>::Output;
+
+/// The quotient of Euclidean division of `A` by `B`, as a [`typenum::Integer`].
+///
+/// Unlike `Quot` followed by truncation, the remainder `RemEuclid<A, B>` is never negative.
+///
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(DivEuclid::<Ratio<N7, P2>, Ratio<P1>>::to_i32(), -4);
+/// assert_eq!(DivEuclid::<Ratio<N7, P2>, Ratio<N1>>::to_i32(), 4);
+/// ```
+pub type DivEuclid<A, B> = <A as EuclideanDivision<B>>::Output;
+
+/// The non-negative remainder of Euclidean division of `A` by `B`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// // Wrapping a phase of -1/4 of a turn into [0, 1).
+/// assert_eq!(RemEuclid::<Ratio<N1, P4>, Ratio<P1>>::default(), Ratio::<P3, P4>::default());
+/// ```
+pub type RemEuclid<A, B> = <A as EuclideanRemainder<B>>::Output;
+
+/// `R` rounded to `F` significant decimal digits, with ties rounded away from zero.
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert_eq!(RoundSigFigs::<Ratio<P2, P3>, P3>::default(), Ratio::<P667, P1000>::default());
+/// assert_eq!(RoundSigFigs::<Ratio<N987>, P2>::default(), Ratio::<N990>::default());
+/// ```
+pub type RoundSigFigs<R, F> = <R as SignificantFigures<F>>::Output;
+
+/// Whether `R` is exactly representable as an `f64`, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsExactF64::<Ratio<P3, P8>>::to_bool());
+/// assert!(!IsExactF64::<Ratio<P1, P10>>::to_bool());
+/// ```
+pub type IsExactF64<R> = <R as ExactlyRepresentable<f64>>::Output;
+
+/// Whether `R` is exactly representable as an `f32`, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+///
+/// # Examples
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Bit, consts::*};
+/// use typenum_ratio::{Ratio, operator_aliases::*};
+///
+/// assert!(IsExactF32::<Ratio<N5, P4>>::to_bool());
+/// assert!(!IsExactF32::<Ratio<P1, P3>>::to_bool());
+/// ```
+pub type IsExactF32<R> = <R as ExactlyRepresentable<f32>>::Output;
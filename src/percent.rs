@@ -0,0 +1,63 @@
+//! Application of compile-time percentages to integers.
+
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use typenum::Integer;
+
+use super::{DynRatio, Rational};
+use super::private::PercentRange;
+use super::rounding::{HalfUp, RoundingMode};
+
+/// A percentage `R`, applied to integers with the rounding mode `M`.
+///
+/// `R` is the percentage itself, so `Percent<Ratio<P15>>` is 15%. It must lie in the range
+/// `[0, 100]`, which is checked at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{DynRatio, Ratio, percent::Percent, rounding::TowardZero};
+///
+/// type Vat = Percent<Ratio<P35, P2>>; // 17.5%
+///
+/// assert_eq!(Vat::apply(1999u32), 350);
+/// assert_eq!(Vat::apply_with_remainder(1999u32), (350, DynRatio::new(-7, 40)));
+/// assert_eq!(Percent::<Ratio<P35, P2>, TowardZero>::apply(1999u32), 349);
+/// ```
+pub struct Percent<R, M = HalfUp>(PhantomData<(R, M)>);
+
+impl<R, M> Percent<R, M>
+    where R: Rational,
+          M: RoundingMode,
+{
+    /// Returns `x * R / 100`, rounded according to `M`.
+    pub fn apply<T>(x: T) -> T
+        where T: Copy + Into<i128> + TryFrom<i128>,
+    {
+        Self::apply_with_remainder(x).0
+    }
+
+    /// Returns `x * R / 100` rounded according to `M`, along with the exact amount which was
+    /// discarded by rounding.
+    pub fn apply_with_remainder<T>(x: T) -> (T, DynRatio)
+        where T: Copy + Into<i128> + TryFrom<i128>,
+    {
+        const_assert!(PercentRange<R>);
+
+        let num = x.into() * i128::from(R::Num::I64);
+        let den = i128::from(R::Den::I64) * 100;
+        let rounded = M::round_div(num, den);
+
+        // The magnitude of the result never exceeds that of `x`.
+        let result = match T::try_from(rounded) {
+            Ok(result) => result,
+            Err(_) => unreachable!("rounded percentage is out of range"),
+        };
+        (result, DynRatio::from_i128(num - rounded * den, den))
+    }
+}
@@ -0,0 +1,275 @@
+//! Implementation details used by the public API of this crate.
+//!
+//! Nothing in this module is covered by semver guarantees.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+pub use typenum::Integer;
+pub use typenum::type_operators::Pow;
+
+use typenum::{NonZero, Unsigned};
+
+use super::Rational;
+
+/// Asserts during constant evaluation that `R` lies in the closed interval `[0, 1]`.
+pub(crate) struct UnitInterval<R>(PhantomData<R>);
+
+impl<R> UnitInterval<R>
+    where R: Rational,
+{
+    pub(crate) const ASSERT: () = assert!(
+        R::Num::I64 >= 0 && R::Num::I64 <= R::Den::I64,
+        "ratio must be in the range [0, 1]"
+    );
+}
+
+/// Asserts during constant evaluation that `R` lies in the closed interval `[0, 100]`.
+pub(crate) struct PercentRange<R>(PhantomData<R>);
+
+impl<R> PercentRange<R>
+    where R: Rational,
+{
+    pub(crate) const ASSERT: () = assert!(
+        R::Num::I64 >= 0 && R::Num::I64 <= 100 * R::Den::I64,
+        "percentage must be in the range [0, 100]"
+    );
+}
+
+/// Asserts during constant evaluation that `R` is strictly positive.
+pub(crate) struct Positive<R>(PhantomData<R>);
+
+impl<R> Positive<R>
+    where R: Rational,
+{
+    pub(crate) const ASSERT: () = assert!(R::Num::I64 > 0, "ratio must be positive");
+}
+
+/// Appends the bits of a `typenum::Unsigned` to `bits`, most significant first.
+pub trait PrivateBits {
+    fn push_bits(bits: &mut Vec<bool>);
+}
+
+/// Writes a `typenum::Integer` in decimal without converting it to a machine integer.
+pub trait PrivateDecimal {
+    fn write_decimal(f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+/// A node of a linked list of `(numerator, denominator)` pairs built during constant evaluation.
+pub enum RatioNode {
+    Nil,
+    Cons((i64, i64), &'static RatioNode),
+}
+
+/// Compares two rational numbers, using `SameDen` to choose between comparing numerators directly
+/// (`B1`) and cross-multiplication (`B0`).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be compared with `{Rhs}`",
+    note = "both operands must be valid `Ratio`s",
+)]
+pub trait PrivateCmp<Rhs, SameDen> {
+    type Output;
+}
+
+pub type PrivateCompare<A, B, SameDen> = <A as PrivateCmp<B, SameDen>>::Output;
+
+/// Selects the lesser of `Self` and `Rhs` given `Ordering`, the result of comparing them.
+pub trait PrivateMin<Rhs, Ordering> {
+    type Output;
+}
+
+/// Selects the greater of `Self` and `Rhs` given `Ordering`, the result of comparing them.
+pub trait PrivateMax<Rhs, Ordering> {
+    type Output;
+}
+
+/// Advances the Fibonacci pair `(A, B)` by `Self` steps and returns `B/A`.
+pub trait PrivateFibonacci<A, B> {
+    type Output;
+}
+
+/// Computes `floor(log_B(Self/D))`, dispatching on whether `Self/D < 1`.
+pub trait PrivateLogFloor<D, B, Below1> {
+    type Output;
+}
+
+/// Computes `K + floor(log_B(Self/D))` for `Self/D >= 1`, where `Continue` is `Self/D >= B`.
+pub trait PrivateLogUp<D, B, K, Continue> {
+    type Output;
+}
+
+/// Computes `K + floor(log_B(Self/D))` for `Self/D > 0`, where `Continue` is `Self/D < 1`.
+pub trait PrivateLogDown<D, B, K, Continue> {
+    type Output;
+}
+
+/// Converts the floor of a logarithm to its ceiling, given whether the logarithm is exact.
+pub trait PrivateCeilLog<Exact> {
+    type Output;
+}
+
+/// Whether `Self/D` is an integer power of `B`, for reduced `Self/D` and `B >= 2`, as a `Bit`.
+pub trait PrivatePowerOf<B, D> {
+    type Output;
+}
+
+/// The absolute value of a `typenum::Integer` as a `typenum::Unsigned`.
+pub trait PrivateMagnitude {
+    type Output;
+}
+
+/// The reduced numerator and denominator of `Self/D`, which define the `Rational` impl of `Ratio`.
+pub trait PrivateReduce<D> {
+    type Num: Integer;
+    type Den: Integer + NonZero;
+}
+
+/// The reduced numerator and denominator of `Self/D`, which define the `UnsignedRational` impl of
+/// `URatio`. Dispatches on the last bits of `Self` and `D` to avoid computing a GCD if possible.
+pub trait PrivateUReduce<D> {
+    type Num: Unsigned;
+    type Den: Unsigned + NonZero;
+}
+
+/// The reduced numerator and denominator of `Self/D`, computed by dividing both by their GCD.
+pub trait PrivateGcdReduce<D> {
+    type Num: Unsigned;
+    type Den: Unsigned + NonZero;
+}
+
+/// Whether a `typenum::Integer` is negative, as a `Bit`.
+pub trait PrivateNegative {
+    type Output;
+}
+
+/// The `typenum::Integer` with magnitude `Self` which is negative iff `Negative` is `B1`.
+pub trait PrivateWithSign<Negative> {
+    type Output: Integer;
+}
+
+/// The `typenum::Integer` equal to a `typenum::Unsigned`.
+pub trait PrivateSigned {
+    type Output;
+}
+
+/// The `typenum::Unsigned` equal to a non-negative `typenum::Integer`.
+pub trait PrivateUnsigned {
+    type Output;
+}
+
+/// A `typenum::Unsigned` with all trailing zero bits removed.
+pub trait PrivateOddPart {
+    type Output;
+}
+
+/// A non-zero `typenum::Unsigned` with every factor of `F` removed.
+pub trait PrivateWithoutFactor<F> {
+    type Output;
+}
+
+/// Removes every factor of `F` from `Self`, given whether `F` divides `Self`.
+pub trait PrivateWithoutFactorIf<F, Divisible> {
+    type Output;
+}
+
+/// The limits of a binary floating-point format.
+pub trait PrivateFloatFormat {
+    /// The number of bits in the significand, including the implicit bit.
+    type Precision;
+
+    /// The number of bits in the largest finite value.
+    type MaxBits;
+
+    /// The number of bits in the reciprocal of the smallest subnormal value.
+    type MinBits;
+}
+
+/// The sign of a `typenum::Integer`: `N1`, `Z0` or `P1`.
+pub trait PrivateSignum {
+    type Output;
+}
+
+pub type PrivateSign<N> = <N as PrivateSignum>::Output;
+
+/// Rounds `Self/D` to the nearest integer, with ties rounded away from zero, for `D > 0`.
+pub trait PrivateRoundHalfAway<D> {
+    type Output;
+}
+
+/// Rounds `Self/D` to the nearest integer, with ties rounded to even, for `D > 0`.
+///
+/// `IsHalf` is `B1` if `D == 2`, which is the only denominator of a reduced fraction that can be
+/// exactly halfway between two integers.
+pub trait PrivateRoundHalfEven<D, IsHalf> {
+    type Output;
+}
+
+/// The exact `Q`-th root of the integer `Self`, which is only implemented if it exists.
+pub trait PrivateRoot<Q> {
+    type Output;
+}
+
+pub type PrivateRootOf<A, Q> = <A as PrivateRoot<Q>>::Output;
+
+/// Searches for the integer `K` such that `K^Q == Self`, given `Ordering = cmp(K^Q, Self)`.
+pub trait PrivateRootSearch<Q, K, Ordering> {
+    type Output;
+}
+
+/// Whether the integer `Self` is a perfect `Q`-th power, as a `Bit`.
+pub trait PrivatePerfectPower<Q> {
+    type Output;
+}
+
+/// Searches for the integer `K` such that `K^Q == Self`, given `Ordering = cmp(K^Q, Self)`, and
+/// returns `B0` if there is none.
+pub trait PrivatePowerSearch<Q, K, Ordering> {
+    type Output;
+}
+
+/// Expands `Self/D` into its continued-fraction coefficients, for `D > 0`.
+pub trait PrivateContinuedFraction<D> {
+    type Output;
+}
+
+/// Continues the expansion with `D/Self` unless `IsZero` is `B1`.
+pub trait PrivateCFracTail<D, IsZero> {
+    type Output;
+}
+
+/// Finds the path from the root of the Stern–Brocot tree to `Self/Q`, given
+/// `Ordering = cmp(Self, Q)`.
+pub trait PrivateSternBrocot<Q, Ordering> {
+    type Output;
+}
+
+/// The ratio at node `Self` of the Calkin–Wilf tree, where the root is node `1` and the children
+/// of node `n` are nodes `2n` and `2n + 1`.
+pub trait PrivateCalkinWilf {
+    type Output;
+}
+
+/// Inserts `V` into the sorted list `Self`, before the first element that is not less than it.
+pub trait PrivateInsert<V> {
+    type Output;
+}
+
+/// Inserts `V` into the sorted list `Self`, given `Ordering = cmp(V, head)`.
+pub trait PrivateInsertOrd<V, Ordering> {
+    type Output;
+}
+
+/// Builds the list `Start, Start + Step, ...` with `Self` elements.
+pub trait PrivateArithSeq<Start, Step> {
+    type Output;
+}
+
+/// Builds the list `Start, Start * Factor, ...` with `Self` elements.
+pub trait PrivateGeomSeq<Start, Factor> {
+    type Output;
+}
+
+/// Builds the list `Term, Term/K, Term/(K*(K+1)), ...` with `Self + 1` elements.
+pub trait PrivateInvFactorials<Term, K> {
+    type Output;
+}
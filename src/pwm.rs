@@ -0,0 +1,46 @@
+//! Helpers for configuring pulse-width modulation timers from compile-time duty cycles.
+
+use typenum::{Integer, Unsigned};
+
+use super::{Rational, private::UnitInterval};
+
+/// Computes the compare-register value for a timer with period `P` and a duty cycle of `R`.
+///
+/// The exact value `R * P` is rounded to the nearest integer, with ties rounded up. A duty cycle
+/// outside of `[0, 1]` is rejected at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, pwm};
+///
+/// assert_eq!(pwm::compare_value::<Ratio<P1, P3>, U1000>(), 333);
+/// assert_eq!(pwm::compare_value::<Ratio<P1, P8>, U100>(), 13);
+/// ```
+///
+/// ```rust,compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, pwm};
+///
+/// pwm::compare_value::<Ratio<P3, P2>, U1000>();
+/// ```
+pub fn compare_value<R, P>() -> u32
+    where R: Rational,
+          P: Unsigned,
+{
+    const_assert!(UnitInterval<R>);
+
+    let num = R::Num::to_i64() as u128;
+    let den = R::Den::to_i64() as u128;
+    let period = u128::from(P::to_u32());
+
+    // round(num * period / den) = floor((2 * num * period + den) / (2 * den))
+    ((2 * num * period + den) / (2 * den)) as u32
+}
@@ -0,0 +1,99 @@
+//! A token-bucket rate limiter whose rate and burst capacity are known at compile time.
+
+use std::marker::PhantomData;
+
+use typenum::Integer;
+
+use super::{Rational, private::Positive};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// A token-bucket rate limiter which refills at `R` tokens per second, up to a capacity of `B`
+/// tokens.
+///
+/// Time is supplied by the caller as a monotonic timestamp in nanoseconds. The bucket is tracked
+/// in exact fractions of a token, so no refill is lost to rounding even at very low rates.
+/// Non-positive rates or capacities are rejected at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, rate_limit::RateLimiter};
+///
+/// // One token every three seconds, with a burst of two.
+/// let mut limiter = RateLimiter::<Ratio<P1, P3>, Ratio<P2>>::new(0);
+/// assert!(limiter.try_acquire(0, 2));
+/// assert!(!limiter.try_acquire(2_999_999_999, 1));
+/// assert!(limiter.try_acquire(3_000_000_000, 1));
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter<R, B> {
+    // The contents of the bucket, in units of `1/(R::Den * B::Den * 10^9)` tokens.
+    credit: u128,
+    last: u64,
+    _params: PhantomData<(R, B)>,
+}
+
+impl<R, B> RateLimiter<R, B>
+    where R: Rational,
+          B: Rational,
+{
+    /// Creates a full bucket at time `now` (in nanoseconds).
+    pub fn new(now: u64) -> Self {
+        const_assert!(Positive<R>);
+        const_assert!(Positive<B>);
+
+        RateLimiter {
+            credit: Self::capacity(),
+            last: now,
+            _params: PhantomData,
+        }
+    }
+
+    /// Returns the number of whole tokens available at time `now` (in nanoseconds).
+    pub fn available(&mut self, now: u64) -> u64 {
+        self.refill(now);
+        (self.credit / Self::token()) as u64
+    }
+
+    /// Removes `tokens` from the bucket at time `now` (in nanoseconds) if enough are available.
+    ///
+    /// Returns `false`, leaving the bucket unchanged, if there are not.
+    pub fn try_acquire(&mut self, now: u64, tokens: u64) -> bool {
+        self.refill(now);
+
+        let cost = u128::from(tokens) * Self::token();
+        if cost <= self.credit {
+            self.credit -= cost;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn refill(&mut self, now: u64) {
+        // A timestamp earlier than the last one is treated as no time having passed.
+        let elapsed = u128::from(now.saturating_sub(self.last));
+        self.last = self.last.max(now);
+
+        // R tokens/s = R::Num * B::Den units/ns
+        let gained = elapsed
+            .saturating_mul(R::Num::to_i64() as u128)
+            .saturating_mul(B::Den::to_i64() as u128);
+        self.credit = self.credit.saturating_add(gained).min(Self::capacity());
+    }
+
+    /// The number of units in one token.
+    fn token() -> u128 {
+        R::Den::to_i64() as u128 * B::Den::to_i64() as u128 * NANOS_PER_SEC
+    }
+
+    /// The number of units in a full bucket.
+    fn capacity() -> u128 {
+        B::Num::to_i64() as u128 * R::Den::to_i64() as u128 * NANOS_PER_SEC
+    }
+}
@@ -1,20 +1,31 @@
 use std::{cmp, fmt};
+use std::convert::TryFrom;
 use std::marker::PhantomData;
 use std::ops::*;
 
 use typenum::{
-    Bit,
+    B0, B1, Bit,
+    Equal, Greater, Less,
     Integer,
     NonZero,
     Ord,
     P1, Z0,
     NInt, PInt,
+    UInt, UTerm,
     Unsigned,
     operator_aliases::*,
     type_operators::*,
 };
 
-use super::{Rational, operator_aliases::{Num, Den, ReducedRatio}};
+use super::{
+    DisplayPercent, DynRatio, Rational, ToRatio, URatio, UnsignedRational,
+    private::*,
+    type_operators::*,
+    operator_aliases::{Num, Den, Lcmf, Reduce},
+};
+
+type NumOf<R> = <R as Rational>::Num;
+type DenOf<R> = <R as Rational>::Den;
 
 /// A rational number whose value is known at compile time.
 ///
@@ -29,9 +40,32 @@ use super::{Rational, operator_aliases::{Num, Den, ReducedRatio}};
 /// * `N` and `D` are [`typenum::Integer`]s.
 /// * `D` is [`NonZero`].
 ///
+/// In particular, `0/0` is not a `Rational`, and dividing by a zero ratio fails to compile:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate typenum_ratio;
+/// # extern crate typenum;
+/// # use typenum::consts::*;
+/// # use typenum_ratio::{Ratio, Rational};
+/// fn rational<R: Rational>() {}
+///
+/// rational::<Ratio<Z0, Z0>>();
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate typenum_ratio;
+/// # extern crate typenum;
+/// # use typenum::consts::*;
+/// let _ = rat!(Z0/P1) / rat!(Z0/P1);
+/// ```
+///
 /// Most uses of `Ratio` will be as a type parameter. For the rare cases when an instance of a
 /// `Ratio` type is needed, use the [`rat!`] macro to construct one.
 ///
+/// The arithmetic operators accept any [`Rational`] on the right-hand side, including
+/// [`typenum::Integer`]s, [`typenum::Unsigned`]s and user-defined types, and always produce a
+/// reduced `Ratio`.
+///
 /// # Example
 ///
 /// ```
@@ -90,10 +124,47 @@ use super::{Rational, operator_aliases::{Num, Den, ReducedRatio}};
 /// assert_eq!(Sterling::<Pounds>::new(21), Sterling::<Guineas>::new(20))
 /// ```
 ///
+/// # Comparison
+///
+/// `Ratio` implements [`typenum::Cmp`], so typenum's `IsLess`, `IsLessOrEqual`, `IsGreater`,
+/// `IsGreaterOrEqual`, `IsEqual` and `IsNotEqual` operators all work on pairs of ratios. These can
+/// be used to constrain a ratio at compile time:
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{True, consts::*, type_operators::IsLessOrEqual};
+/// use typenum_ratio::Ratio;
+///
+/// /// Scales `x` down by a factor which must not exceed one.
+/// fn attenuate<R>(x: f64) -> f64
+///     where R: IsLessOrEqual<Ratio<P1>, Output = True>,
+///           f64: std::ops::Mul<R, Output = f64>,
+///           R: Default,
+/// {
+///     x * R::default()
+/// }
+///
+/// assert_eq!(attenuate::<Ratio<P3, P4>>(8.0), 6.0);
+/// ```
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_ratio;
+/// # use typenum::{True, consts::*, type_operators::IsLessOrEqual};
+/// # use typenum_ratio::Ratio;
+/// fn attenuate<R>() where R: IsLessOrEqual<Ratio<P1>, Output = True> {}
+///
+/// attenuate::<Ratio<P5, P4>>();
+/// ```
+///
 /// [`Rational`]: ./trait.Rational.html
+/// [`typenum::Cmp`]: https://docs.rs/typenum/1.10.0/typenum/type_operators/trait.Cmp.html
 /// [`NonZero`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.NonZero.html
 /// [`rat!`]: ./macro.rat.html
 /// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+/// [`typenum::Unsigned`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
 /// [reduced]: http://mathworld.wolfram.com/ReducedFraction.html
 pub struct Ratio<N, D = P1>(PhantomData<(N, D)>);
 
@@ -107,62 +178,264 @@ impl<N, D> Ratio<N, D>
     }
 }
 
+impl<N, D> Ratio<N, D>
+    where Ratio<N, D>: Rational,
+{
+    /// Multiplies `x` by this ratio exactly.
+    ///
+    /// Returns the product as a [`DynRatio`], or `None` if its reduced numerator does not fit in
+    /// an `i64`. `Ratio * x` returns the product as an integer instead, if it is whole.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate typenum;
+    /// extern crate typenum_ratio;
+    ///
+    /// use typenum::consts::*;
+    /// use typenum_ratio::{DynRatio, Ratio};
+    ///
+    /// assert_eq!(Ratio::<P3, P4>::scale(8), Some(DynRatio::from_integer(6)));
+    /// assert_eq!(Ratio::<P3, P4>::scale(6u64), Some(DynRatio::new(9, 2)));
+    /// assert_eq!(Ratio::<P3, P2>::scale(i64::max_value()), None);
+    /// ```
+    ///
+    /// [`DynRatio`]: ./struct.DynRatio.html
+    pub fn scale<T>(x: T) -> Option<DynRatio>
+        where T: Into<i128>,
+    {
+        DynRatio::checked_from_i128(
+            x.into() * i128::from(Num::<N, D>::to_i64()),
+            Den::<N, D>::to_i64().into())
+    }
+
+    /// Converts this ratio to the nearest `f64`, also returning the exact rounding error.
+    ///
+    /// See [`DynRatio::to_f64_with_error`] for details.
+    ///
+    /// [`DynRatio::to_f64_with_error`]: ./struct.DynRatio.html#method.to_f64_with_error
+    pub fn to_f64_with_error(self) -> (f64, (DynRatio, i32)) {
+        DynRatio::from(self).to_f64_with_error()
+    }
+
+    /// Renders the exact expansion of this ratio in the given base.
+    ///
+    /// See [`DynRatio::to_string_radix`] for details.
+    ///
+    /// [`DynRatio::to_string_radix`]: ./struct.DynRatio.html#method.to_string_radix
+    pub fn to_string_radix(self, base: u32, max_digits: usize) -> String {
+        DynRatio::from(self).to_string_radix(base, max_digits)
+    }
+
+    /// Returns an object which displays this ratio as a percentage with exactly `places` decimal
+    /// places.
+    ///
+    /// See [`DynRatio::display_percent`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate typenum_ratio;
+    /// extern crate typenum;
+    ///
+    /// use typenum::consts::*;
+    ///
+    /// assert_eq!(rat!(P7/P10).display_percent(1).to_string(), "70.0%");
+    /// ```
+    ///
+    /// [`DynRatio::display_percent`]: ./struct.DynRatio.html#method.display_percent
+    pub fn display_percent(self, places: usize) -> DisplayPercent {
+        DynRatio::from(self).display_percent(places)
+    }
+}
+
+/// A ratio whose reduced denominator is one is the integer it is equal to.
+///
+/// # Example
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::Ratio;
+///
+/// fn squared<I: Integer>() -> i64 {
+///     I::to_i64() * I::I64
+/// }
+///
+/// assert_eq!(squared::<Ratio<N12, P4>>(), 9);
+/// assert_eq!(Ratio::<P1000, P8>::to_i8(), 125);
+/// ```
+///
+/// A ratio which is not whole is not an `Integer`:
+///
+/// ```compile_fail
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::{Integer, consts::*};
+/// use typenum_ratio::Ratio;
+///
+/// let _ = Ratio::<P3, P2>::to_i8();
+/// ```
+impl<N, D> Integer for Ratio<N, D>
+    where Ratio<N, D>: Rational<Den = P1>,
+{
+    const I8: i8 = <Num<N, D> as Integer>::I8;
+    const I16: i16 = <Num<N, D> as Integer>::I16;
+    const I32: i32 = <Num<N, D> as Integer>::I32;
+    const I64: i64 = <Num<N, D> as Integer>::I64;
+    const ISIZE: isize = <Num<N, D> as Integer>::ISIZE;
+
+    fn to_i8() -> i8 {
+        Num::<N, D>::to_i8()
+    }
+
+    fn to_i16() -> i16 {
+        Num::<N, D>::to_i16()
+    }
+
+    fn to_i32() -> i32 {
+        Num::<N, D>::to_i32()
+    }
+
+    fn to_i64() -> i64 {
+        Num::<N, D>::to_i64()
+    }
+
+    fn to_isize() -> isize {
+        Num::<N, D>::to_isize()
+    }
+}
+
 impl<N, D> Default for Ratio<N, D> {
     fn default() -> Self {
         Ratio(PhantomData)
     }
 }
 
+/// N/D => sign(N)*sign(D)*|N|/|D|, reduced
+///
+/// The magnitudes are reduced as a `URatio`, so there is a single reduction path for every
+/// combination of signs.
+impl<N, D> Rational for Ratio<N, D>
+    where N: PrivateReduce<D>,
+{
+    type Num = <N as PrivateReduce<D>>::Num;
+    type Den = <N as PrivateReduce<D>>::Den;
+}
+
+type Mag<I> = <I as PrivateMagnitude>::Output;
+type IsNeg<I> = <I as PrivateNegative>::Output;
+type MagNum<N, D> = <URatio<Mag<N>, Mag<D>> as UnsignedRational>::Num;
+type MagDen<N, D> = <URatio<Mag<N>, Mag<D>> as UnsignedRational>::Den;
+
 /// 0/D => 0/1
-impl<D> Rational for Ratio<Z0, D>
-    where D: Integer,
+impl<D> PrivateReduce<D> for Z0
+    where D: Integer + NonZero,
+{
+    type Num = Z0;
+    type Den = P1;
+}
+
+/// +N/D => sign(D)*N/|D|
+impl<U, D> PrivateReduce<D> for PInt<U>
+    where U: Unsigned + NonZero,
+          D: PrivateMagnitude + PrivateNegative,
+          URatio<U, Mag<D>>: UnsignedRational,
+          MagNum<PInt<U>, D>: PrivateWithSign<IsNeg<D>>,
+{
+    type Num = <MagNum<PInt<U>, D> as PrivateWithSign<IsNeg<D>>>::Output;
+    type Den = PInt<MagDen<PInt<U>, D>>;
+}
+
+/// -N/D => -sign(D)*N/|D|
+impl<U, D> PrivateReduce<D> for NInt<U>
+    where U: Unsigned + NonZero,
+          D: PrivateMagnitude + PrivateNegative,
+          IsNeg<D>: Not,
+          URatio<U, Mag<D>>: UnsignedRational,
+          MagNum<NInt<U>, D>: PrivateWithSign<<IsNeg<D> as Not>::Output>,
 {
+    type Num = <MagNum<NInt<U>, D> as PrivateWithSign<<IsNeg<D> as Not>::Output>>::Output;
+    type Den = PInt<MagDen<NInt<U>, D>>;
+}
+
+impl PrivateNegative for Z0 {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero> PrivateNegative for PInt<U> {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero> PrivateNegative for NInt<U> {
+    type Output = B1;
+}
+
+impl<S: Bit> PrivateWithSign<S> for UTerm {
+    type Output = Z0;
+}
+
+impl<U: Unsigned, B: Bit> PrivateWithSign<B0> for UInt<U, B> {
+    type Output = PInt<UInt<U, B>>;
+}
+
+impl<U: Unsigned, B: Bit> PrivateWithSign<B1> for UInt<U, B> {
+    type Output = NInt<UInt<U, B>>;
+}
+
+/// I => I/1
+impl Rational for Z0 {
     type Num = Z0;
     type Den = P1;
 }
 
-/// N/D => N/D
-impl<N, D> Rational for Ratio<PInt<N>, PInt<D>>
-    where N: Unsigned + NonZero + Div<Gcf<N, D>> + Gcd<D>,
-          D: Unsigned + NonZero + Div<Gcf<N, D>>,
-          Quot<N, Gcf<N, D>>: Unsigned + NonZero,
-          Quot<D, Gcf<N, D>>: Unsigned + NonZero,
+impl<U> Rational for PInt<U>
+    where U: Unsigned + NonZero,
+{
+    type Num = PInt<U>;
+    type Den = P1;
+}
+
+impl<U> Rational for NInt<U>
+    where U: Unsigned + NonZero,
 {
-    type Num = PInt<Quot<N, Gcf<N, D>>>;
-    type Den = PInt<Quot<D, Gcf<N, D>>>;
+    type Num = NInt<U>;
+    type Den = P1;
+}
+
+/// U => +U/1
+impl Rational for UTerm {
+    type Num = Z0;
+    type Den = P1;
 }
 
-/// N/-D => -N/D
-impl<N, D> Rational for Ratio<PInt<N>, NInt<D>>
-    where N: Unsigned + NonZero + Div<Gcf<N, D>> + Gcd<D>,
-          D: Unsigned + NonZero + Div<Gcf<N, D>>,
-          Quot<N, Gcf<N, D>>: Unsigned + NonZero,
-          Quot<D, Gcf<N, D>>: Unsigned + NonZero,
+impl<U, B> Rational for UInt<U, B>
+    where U: Unsigned,
+          B: Bit,
 {
-    type Num = NInt<Quot<N, Gcf<N, D>>>;
-    type Den = PInt<Quot<D, Gcf<N, D>>>;
+    type Num = PInt<UInt<U, B>>;
+    type Den = P1;
 }
 
-/// -N/D => -N/D
-impl<N, D> Rational for Ratio<NInt<N>, PInt<D>>
-    where N: Unsigned + NonZero + Div<Gcf<N, D>> + Gcd<D>,
-          D: Unsigned + NonZero + Div<Gcf<N, D>>,
-          Quot<N, Gcf<N, D>>: Unsigned + NonZero,
-          Quot<D, Gcf<N, D>>: Unsigned + NonZero,
+/// R => Num/Den
+impl<R> ToRatio for R
+    where R: Rational,
+          Ratio<R::Num, R::Den>: Rational,
 {
-    type Num = NInt<Quot<N, Gcf<N, D>>>;
-    type Den = PInt<Quot<D, Gcf<N, D>>>;
+    type Output = Ratio<R::Num, R::Den>;
 }
 
-/// -N/-D => N/D
-impl<N, D> Rational for Ratio<NInt<N>, NInt<D>>
-    where N: Unsigned + NonZero + Div<Gcf<N, D>> + Gcd<D>,
-          D: Unsigned + NonZero + Div<Gcf<N, D>>,
-          Quot<N, Gcf<N, D>>: Unsigned + NonZero,
-          Quot<D, Gcf<N, D>>: Unsigned + NonZero,
+/// Two ratios are the same value iff their reduced forms are the same type
+impl<N1, D1, N2, D2> SameRatio<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Rational,
+          Ratio<N2, D2>: Rational,
+          Num<N1, D1>: Same<Num<N2, D2>>,
+          Den<N1, D1>: Same<Den<N2, D2>>,
 {
-    type Num = PInt<Quot<N, Gcf<N, D>>>;
-    type Den = PInt<Quot<D, Gcf<N, D>>>;
+    type Output = Reduce<N1, D1>;
 }
 
 /// N1/D1 == N2/D2 for two reduced fractions iff N1 == N2 && N2 == D2
@@ -183,210 +456,322 @@ impl<N, D> cmp::Eq for Ratio<N, D>
     where Ratio<N, D>: Rational + PartialEq,
 {}
 
+/// N1/D < N2/D iff N1 < N2
+impl<N1, D1, N2, D2> PrivateCmp<Ratio<N2, D2>, B1> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Rational,
+          Ratio<N2, D2>: Rational,
+          Num<N1, D1>: Cmp<Num<N2, D2>>,
+{
+    type Output = Compare<Num<N1, D1>, Num<N2, D2>>;
+}
+
 /// N1/D1 < N2/D2 iff N1*D2 < N2*D1
-impl<N1, D1, N2, D2> PartialOrd<Ratio<N2, D2>> for Ratio<N1, D1>
-    where Ratio<N1, D1>: Rational + PartialEq<Ratio<N2, D2>>,
+impl<N1, D1, N2, D2> PrivateCmp<Ratio<N2, D2>, B0> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Rational,
           Ratio<N2, D2>: Rational,
           Num<N1, D1>: Mul<Den<N2, D2>>,
           Num<N2, D2>: Mul<Den<N1, D1>>,
           Prod<Num<N1, D1>, Den<N2, D2>>: Cmp<Prod<Num<N2, D2>, Den<N1, D1>>>,
-          Compare<Prod<Num<N1, D1>, Den<N2, D2>>, Prod<Num<N2, D2>, Den<N1, D1>>>: Ord,
 {
-    fn partial_cmp(&self, _: &Ratio<N2, D2>) -> Option<cmp::Ordering> {
-        Compare::<
-            Prod<Num<N1, D1>, Den<N2, D2>>,
-            Prod<Num<N2, D2>, Den<N1, D1>>
-        >::to_ordering().into()
-    }
+    type Output = Compare<Prod<Num<N1, D1>, Den<N2, D2>>, Prod<Num<N2, D2>, Den<N1, D1>>>;
 }
 
-impl<N, D> cmp::Ord for Ratio<N, D>
-    where Ratio<N, D>: Rational + PartialOrd,
+/// Ratios with the same reduced denominator are compared by their numerators alone, which avoids
+/// building large intermediate products.
+impl<N1, D1, N2, D2> Cmp<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Rational,
+          Ratio<N2, D2>: Rational,
+          Den<N1, D1>: IsEqual<Den<N2, D2>>,
+          Ratio<N1, D1>: PrivateCmp<Ratio<N2, D2>, Eq<Den<N1, D1>, Den<N2, D2>>>,
 {
-    fn cmp(&self, _: &Self) -> cmp::Ordering {
-        cmp::Ordering::Equal
-    }
+    type Output = PrivateCompare<Ratio<N1, D1>, Ratio<N2, D2>, Eq<Den<N1, D1>, Den<N2, D2>>>;
 }
 
-/// (N1/D1) + (N2/D2) = (N1*D2 + N2*D1)/(D1*D2)
-impl<N1, D1, N2, D2> Add<Ratio<N2, D2>> for Ratio<N1, D1>
-    where N1: Mul<D2>,
-          N2: Mul<D1>,
-          D1: Mul<D2>,
-          Prod<N1, D2>: Add<Prod<N2, D1>>,
-          Ratio<Sum<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>: Rational,
+impl<A, B> PrivateMin<B, Less> for A {
+    type Output = A;
+}
+
+impl<A, B> PrivateMin<B, Equal> for A {
+    type Output = A;
+}
+
+impl<A, B> PrivateMin<B, Greater> for A {
+    type Output = B;
+}
+
+impl<A, B> PrivateMax<B, Less> for A {
+    type Output = B;
+}
+
+impl<A, B> PrivateMax<B, Equal> for A {
+    type Output = A;
+}
 
+impl<A, B> PrivateMax<B, Greater> for A {
+    type Output = A;
+}
+
+impl<N1, D1, N2, D2> Min<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Cmp<Ratio<N2, D2>>,
+          Ratio<N1, D1>: PrivateMin<Ratio<N2, D2>, Compare<Ratio<N1, D1>, Ratio<N2, D2>>>,
+          <Ratio<N1, D1> as PrivateMin<Ratio<N2, D2>, Compare<Ratio<N1, D1>, Ratio<N2, D2>>>>::Output:
+              Default,
 {
     type Output =
-        ReducedRatio<
-            Sum<Prod<N1, D2>, Prod<N2, D1>>,
-            Prod<D1, D2>
-        >;
+        <Ratio<N1, D1> as PrivateMin<Ratio<N2, D2>, Compare<Ratio<N1, D1>, Ratio<N2, D2>>>>::Output;
 
-    fn add(self, _: Ratio<N2, D2>) -> Self::Output {
+    fn min(self, _: Ratio<N2, D2>) -> Self::Output {
         Default::default()
     }
 }
 
-/// (N/D) + I = (N+D*I)/D
-impl<N, D, I> Add<I> for Ratio<N, D>
-    where I: Integer,
-          D: Mul<I>,
-          N: Add<Prod<D, I>>,
-          Ratio<Sum<N, Prod<D, I>>, D>: Rational,
+impl<N1, D1, N2, D2> Max<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Cmp<Ratio<N2, D2>>,
+          Ratio<N1, D1>: PrivateMax<Ratio<N2, D2>, Compare<Ratio<N1, D1>, Ratio<N2, D2>>>,
+          <Ratio<N1, D1> as PrivateMax<Ratio<N2, D2>, Compare<Ratio<N1, D1>, Ratio<N2, D2>>>>::Output:
+              Default,
 {
-    // No need to reduce.
-    type Output = Ratio<Sum<N, Prod<D, I>>, D>;
+    type Output =
+        <Ratio<N1, D1> as PrivateMax<Ratio<N2, D2>, Compare<Ratio<N1, D1>, Ratio<N2, D2>>>>::Output;
 
-    fn add(self, _: I) -> Self::Output {
+    fn max(self, _: Ratio<N2, D2>) -> Self::Output {
         Default::default()
     }
 }
 
-/// (N1/D1) - (N2/D2) = (N1*D2 - N2*D1)/(D1*D2)
-impl<N1, D1, N2, D2> Sub<Ratio<N2, D2>> for Ratio<N1, D1>
-    where N1: Mul<D2>,
-          N2: Mul<D1>,
-          D1: Mul<D2>,
-          Prod<N1, D2>: Sub<Prod<N2, D1>>,
-          Ratio<Diff<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>: Rational,
+/// clamp(R, Lo, Hi) = min(max(R, Lo), Hi)
+impl<N, D, Lo, Hi> ClampTo<Lo, Hi> for Ratio<N, D>
+    where Lo: IsLessOrEqual<Hi, Output = B1>,
+          Ratio<N, D>: Max<Lo>,
+          Maximum<Ratio<N, D>, Lo>: Min<Hi>,
+{
+    type Output = Minimum<Maximum<Ratio<N, D>, Lo>, Hi>;
+}
 
+impl<N1, D1, N2, D2> PartialOrd<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Rational + PartialEq<Ratio<N2, D2>> + Cmp<Ratio<N2, D2>>,
+          Compare<Ratio<N1, D1>, Ratio<N2, D2>>: Ord,
 {
-    type Output =
-        ReducedRatio<
-            Diff<Prod<N1, D2>, Prod<N2, D1>>,
-            Prod<D1, D2>
-        >;
+    fn partial_cmp(&self, _: &Ratio<N2, D2>) -> Option<cmp::Ordering> {
+        Compare::<Ratio<N1, D1>, Ratio<N2, D2>>::to_ordering().into()
+    }
+}
 
-    fn sub(self, _: Ratio<N2, D2>) -> Self::Output {
-        Default::default()
+impl<N, D> cmp::Ord for Ratio<N, D>
+    where Ratio<N, D>: Rational + PartialOrd,
+{
+    fn cmp(&self, _: &Self) -> cmp::Ordering {
+        cmp::Ordering::Equal
     }
 }
 
-/// (N/D) - I = (N-D*I)/D
-impl<N, D, I> Sub<I> for Ratio<N, D>
-    where I: Integer,
-          D: Mul<I>,
-          N: Sub<Prod<D, I>>,
-          Ratio<Diff<N, Prod<D, I>>, D>: Rational,
+/// (N1/D1) + (N2/D2) = (N1*D2 + N2*D1)/(D1*D2)
+impl<N1, D1, R> Add<R> for Ratio<N1, D1>
+    where R: Rational,
+          N1: Mul<DenOf<R>>,
+          NumOf<R>: Mul<D1>,
+          D1: Mul<DenOf<R>>,
+          Prod<N1, DenOf<R>>: Add<Prod<NumOf<R>, D1>>,
+          Ratio<Sum<Prod<N1, DenOf<R>>, Prod<NumOf<R>, D1>>, Prod<D1, DenOf<R>>>: Rational,
 {
-    // No need to reduce.
-    type Output = Ratio<Diff<N, Prod<D, I>>, D>;
+    type Output =
+        Reduce<
+            Sum<Prod<N1, DenOf<R>>, Prod<NumOf<R>, D1>>,
+            Prod<D1, DenOf<R>>
+        >;
 
-    fn sub(self, _: I) -> Self::Output {
+    fn add(self, _: R) -> Self::Output {
         Default::default()
     }
 }
 
-/// (N1/D1) * (N2/D2) = (N1*N2)/(D1*D2)
-impl<N1, D1, N2, D2> Mul<Ratio<N2, D2>> for Ratio<N1, D1>
-    where N1: Mul<N2>,
-          D1: Mul<D2>,
-          Ratio<Prod<N1, N2>, Prod<D1, D2>>: Rational,
+/// (N1/D1) - (N2/D2) = (N1*D2 - N2*D1)/(D1*D2)
+impl<N1, D1, R> Sub<R> for Ratio<N1, D1>
+    where R: Rational,
+          N1: Mul<DenOf<R>>,
+          NumOf<R>: Mul<D1>,
+          D1: Mul<DenOf<R>>,
+          Prod<N1, DenOf<R>>: Sub<Prod<NumOf<R>, D1>>,
+          Ratio<Diff<Prod<N1, DenOf<R>>, Prod<NumOf<R>, D1>>, Prod<D1, DenOf<R>>>: Rational,
 {
-    type Output = ReducedRatio<Prod<N1, N2>, Prod<D1, D2>>;
+    type Output =
+        Reduce<
+            Diff<Prod<N1, DenOf<R>>, Prod<NumOf<R>, D1>>,
+            Prod<D1, DenOf<R>>
+        >;
 
-    fn mul(self, _: Ratio<N2, D2>) -> Self::Output {
+    fn sub(self, _: R) -> Self::Output {
         Default::default()
     }
 }
 
-/// (N/D) * I = (N*I)/D
-impl<N, D, I> Mul<I> for Ratio<N, D>
-    where I: Integer,
-          N: Mul<I>,
-          Ratio<Prod<N, I>, D>: Rational,
+/// (N1/D1) * (N2/D2) = (N1*N2)/(D1*D2)
+impl<N1, D1, R> Mul<R> for Ratio<N1, D1>
+    where R: Rational,
+          N1: Mul<NumOf<R>>,
+          D1: Mul<DenOf<R>>,
+          Ratio<Prod<N1, NumOf<R>>, Prod<D1, DenOf<R>>>: Rational,
 {
-    type Output = ReducedRatio<Prod<N, I>, D>;
+    type Output = Reduce<Prod<N1, NumOf<R>>, Prod<D1, DenOf<R>>>;
 
-    fn mul(self, _: I) -> Self::Output {
+    fn mul(self, _: R) -> Self::Output {
         Default::default()
     }
 }
 
 /// (N1/D1) / (N2/D2) = (N1*D2)/(D1*N2)
-impl<N1, D1, N2, D2> Div<Ratio<N2, D2>> for Ratio<N1, D1>
-    where N1: Mul<D2>,
-          D1: Mul<N2>,
-          Ratio<Prod<N1, D2>, Prod<D1, N2>>: Rational,
+impl<N1, D1, R> Div<R> for Ratio<N1, D1>
+    where R: Rational,
+          N1: Mul<DenOf<R>>,
+          D1: Mul<NumOf<R>>,
+          Ratio<Prod<N1, DenOf<R>>, Prod<D1, NumOf<R>>>: Rational,
 {
-    type Output = ReducedRatio<Prod<N1, D2>, Prod<D1, N2>>;
+    type Output = Reduce<Prod<N1, DenOf<R>>, Prod<D1, NumOf<R>>>;
 
-    fn div(self, _: Ratio<N2, D2>) -> Self::Output {
+    fn div(self, _: R) -> Self::Output {
         Default::default()
     }
 }
 
-/// (N/D) / I = N/(D*I)
-impl<N, D, I> Div<I> for Ratio<N, D>
-    where I: Integer,
-          D: Mul<I>,
-          Ratio<N, Prod<D, I>>: Rational,
+/// (N1/D1) % (N2/D2) = (N1*D2 % N2*D1)/(D1*D2)
+impl<N1, D1, R> Rem<R> for Ratio<N1, D1>
+    where R: Rational,
+          N1: Mul<DenOf<R>>,
+          NumOf<R>: Mul<D1>,
+          D1: Mul<DenOf<R>>,
+          Prod<N1, DenOf<R>>: Rem<Prod<NumOf<R>, D1>>,
+          Ratio<Mod<Prod<N1, DenOf<R>>, Prod<NumOf<R>, D1>>, Prod<D1, DenOf<R>>>: Rational,
 {
-    type Output = ReducedRatio<N, Prod<D, I>>;
+    type Output =
+        Reduce<
+            Mod<Prod<N1, DenOf<R>>, Prod<NumOf<R>, D1>>,
+            Prod<D1, DenOf<R>>,
+        >;
 
-    fn div(self, _: I) -> Self::Output {
+    fn rem(self, _: R) -> Self::Output {
         Default::default()
     }
 }
 
-/// (N1/D1) % (N2/D2) = (N1*D2 % N2*D1)/(D1*D2)
-impl<N1, D1, N2, D2> Rem<Ratio<N2, D2>> for Ratio<N1, D1>
-    where N1: Mul<D2>,
-          N2: Mul<D1>,
-          D1: Mul<D2>,
-          Prod<N1, D2>: Rem<Prod<N2, D1>>,
-          Ratio<Mod<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>: Rational,
-
+/// gcd(N1/D1, N2/D2) = gcd(N1*D2, N2*D1)/(D1*D2)
+impl<N1, D1, R> Gcd<R> for Ratio<N1, D1>
+    where R: Rational,
+          N1: Mul<DenOf<R>>,
+          NumOf<R>: Mul<D1>,
+          D1: Mul<DenOf<R>>,
+          Prod<N1, DenOf<R>>: Gcd<Prod<NumOf<R>, D1>>,
+          Ratio<Gcf<Prod<N1, DenOf<R>>, Prod<NumOf<R>, D1>>, Prod<D1, DenOf<R>>>: Rational,
 {
     type Output =
-        ReducedRatio<
-            Mod<Prod<N1, D2>, Prod<N2, D1>>,
-            Prod<D1, D2>,
+        Reduce<
+            Gcf<Prod<N1, DenOf<R>>, Prod<NumOf<R>, D1>>,
+            Prod<D1, DenOf<R>>,
         >;
+}
 
-    fn rem(self, _: Ratio<N2, D2>) -> Self::Output {
-        Default::default()
-    }
+type AbsNum<N, D> = AbsVal<Num<N, D>>;
+type AbsNumOf<R> = AbsVal<NumOf<R>>;
+type LcmNum<N1, D1, R> =
+    Quot<Prod<AbsNum<N1, D1>, AbsNumOf<R>>, Gcf<AbsNum<N1, D1>, AbsNumOf<R>>>;
+
+/// lcm(N1/D1, N2/D2) = lcm(N1, N2)/gcd(D1, D2) for reduced fractions
+impl<N1, D1, R> Lcm<R> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Rational,
+          R: Rational,
+          Num<N1, D1>: Abs,
+          NumOf<R>: Abs,
+          AbsNum<N1, D1>: Mul<AbsNumOf<R>> + Gcd<AbsNumOf<R>>,
+          Prod<AbsNum<N1, D1>, AbsNumOf<R>>: Div<Gcf<AbsNum<N1, D1>, AbsNumOf<R>>>,
+          Den<N1, D1>: Gcd<DenOf<R>>,
+          Ratio<LcmNum<N1, D1, R>, Gcf<Den<N1, D1>, DenOf<R>>>: Rational,
+{
+    type Output = Reduce<LcmNum<N1, D1, R>, Gcf<Den<N1, D1>, DenOf<R>>>;
+}
+
+// `Gcd` and `Lcm` are commutative, so an integer on the left defers to the ratio on the right.
+macro_rules! impl_gcd_lcm_integer_lhs {
+    ($($t:ty, [$($g:ident),*]);*) => {$(
+        /// gcd(I, N/D) = gcd(N/D, I)
+        impl<N, D, $($g),*> Gcd<Ratio<N, D>> for $t
+            where $t: Rational,
+                  Ratio<N, D>: Gcd<$t>,
+        {
+            type Output = Gcf<Ratio<N, D>, $t>;
+        }
+
+        /// lcm(I, N/D) = lcm(N/D, I)
+        impl<N, D, $($g),*> Lcm<Ratio<N, D>> for $t
+            where $t: Rational,
+                  Ratio<N, D>: Lcm<$t>,
+        {
+            type Output = Lcmf<Ratio<N, D>, $t>;
+        }
+    )*}
+}
+
+impl_gcd_lcm_integer_lhs!(Z0, []; PInt<U>, [U]; NInt<U>, [U]; UTerm, []; UInt<U, B>, [U, B]);
+
+/// A || B = (A*B)/(A + B)
+impl<N1, D1, N2, D2> CombineParallel<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Mul<Ratio<N2, D2>> + Add<Ratio<N2, D2>>,
+          Prod<Ratio<N1, D1>, Ratio<N2, D2>>: Div<Sum<Ratio<N1, D1>, Ratio<N2, D2>>>,
+{
+    type Output = Quot<Prod<Ratio<N1, D1>, Ratio<N2, D2>>, Sum<Ratio<N1, D1>, Ratio<N2, D2>>>;
 }
 
-/// (N/D) % I = (N % I*D)/D
-impl<N, D, I> Rem<I> for Ratio<N, D>
-    where I: Integer + Mul<D>,
-          N: Rem<Prod<I, D>>,
-          Ratio<Mod<N, Prod<I, D>>, D>: Rational,
+/// divider(A, B) = A/(A + B)
+impl<N1, D1, N2, D2> DividerRatio<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Add<Ratio<N2, D2>>
+                       + Div<Sum<Ratio<N1, D1>, Ratio<N2, D2>>>,
 {
-    type Output = ReducedRatio<
-        Mod<N, Prod<I, D>>,
-        D,
-    >;
+    type Output = Quot<Ratio<N1, D1>, Sum<Ratio<N1, D1>, Ratio<N2, D2>>>;
+}
 
-    fn rem(self, _: I) -> Self::Output {
-        Default::default()
-    }
+/// divider(A, B) = T => B = A*(1 - T)/T
+impl<N1, D1, N2, D2> DividerSolve<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<P1>: Sub<Ratio<N2, D2>>,
+          Ratio<N1, D1>: Mul<Diff<Ratio<P1>, Ratio<N2, D2>>>,
+          Prod<Ratio<N1, D1>, Diff<Ratio<P1>, Ratio<N2, D2>>>: Div<Ratio<N2, D2>>,
+{
+    type Output = Quot<Prod<Ratio<N1, D1>, Diff<Ratio<P1>, Ratio<N2, D2>>>, Ratio<N2, D2>>;
 }
 
-/// gcd(N1/D1, N2/D2) = gcd(N1*D2, N2*D1)/(D1*D2)
-impl<N1, D1, N2, D2> Gcd<Ratio<N2, D2>> for Ratio<N1, D1>
-    where N1: Mul<D2>,
-          N2: Mul<D1>,
-          D1: Mul<D2>,
-          Prod<N1, D2>: Gcd<Prod<N2, D1>>,
-          Ratio<Gcf<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>: Rational,
+/// |N/D| = |N|/D
+impl<N, D> Abs for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: Abs,
+{
+    type Output = Ratio<AbsVal<Num<N, D>>, Den<N, D>>;
+}
 
+/// 1/(N/D) = D/N
+impl<N, D> Inv for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: NonZero,
+          Ratio<Den<N, D>, Num<N, D>>: Rational,
 {
-    type Output =
-        ReducedRatio<
-            Gcf<Prod<N1, D2>, Prod<N2, D1>>,
-            Prod<D1, D2>,
-        >;
+    type Output = Reduce<Den<N, D>, Num<N, D>>;
 }
 
-impl<N, D, I> Gcd<I> for Ratio<N, D>
-    where I: Integer,
-          Ratio<N, D>: Gcd<Ratio<I, P1>>,
+impl<N, D> RatioSignum for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: PrivateSignum,
 {
-    type Output = Gcf<Ratio<N, D>, Ratio<I, P1>>;
+    type Output = PrivateSign<Num<N, D>>;
+}
+
+impl<N, D> IntegralRatio for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Den<N, D>: IsEqual<P1>,
+{
+    type Output = Eq<Den<N, D>, P1>;
+}
+
+/// N/1 => N
+impl<R> WholeRatio for R
+    where R: Rational<Den = P1>,
+{
+    type Output = R::Num;
 }
 
 impl<N, D> Neg for Ratio<N, D>
@@ -400,35 +785,244 @@ impl<N, D> Neg for Ratio<N, D>
     }
 }
 
-impl<N, D, I> Pow<I> for Ratio<N, D>
+/// (N/D)^0 = 1
+impl<N, D> Pow<Z0> for Ratio<N, D>
     where Ratio<N, D>: Rational,
-          Num<N, D>: Pow<I>,
-          Den<N, D>: Pow<I>,
-          Ratio<Exp<Num<N, D>, I>, Exp<Den<N, D>, I>>: Rational,
+{
+    type Output = Ratio<P1>;
+
+    fn powi(self, _: Z0) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// (N/D)^E = N^E/D^E
+impl<N, D, E> Pow<PInt<E>> for Ratio<N, D>
+    where E: Unsigned + NonZero,
+          Ratio<N, D>: Rational,
+          Num<N, D>: Pow<PInt<E>>,
+          Den<N, D>: Pow<PInt<E>>,
+          Ratio<Exp<Num<N, D>, PInt<E>>, Exp<Den<N, D>, PInt<E>>>: Rational,
+{
+    type Output = Reduce<Exp<Num<N, D>, PInt<E>>, Exp<Den<N, D>, PInt<E>>>;
+
+    fn powi(self, _: PInt<E>) -> Self::Output {
+        Default::default()
+    }
+}
 
+/// (N/D)^-E = D^E/N^E
+impl<N, D, E> Pow<NInt<E>> for Ratio<N, D>
+    where E: Unsigned + NonZero,
+          Ratio<N, D>: Rational,
+          Num<N, D>: Pow<PInt<E>>,
+          Den<N, D>: Pow<PInt<E>>,
+          Ratio<Exp<Den<N, D>, PInt<E>>, Exp<Num<N, D>, PInt<E>>>: Rational,
 {
-    type Output = ReducedRatio<Exp<Num<N, D>, I>, Exp<Den<N, D>, I>>;
+    type Output = Reduce<Exp<Den<N, D>, PInt<E>>, Exp<Num<N, D>, PInt<E>>>;
 
-    fn powi(self, _: I) -> Self::Output {
+    fn powi(self, _: NInt<E>) -> Self::Output {
         Default::default()
     }
 }
 
-// TODO: Can't implement e.g `Div<Ratio<N, D>> for PInt<U>` due to coherence issues.
-// Maybe add a feature to `typenum`?
+/// (N/D)^U0 = (N/D)^0
+impl<N, D> Pow<UTerm> for Ratio<N, D>
+    where Ratio<N, D>: Pow<Z0>,
+          Exp<Ratio<N, D>, Z0>: Default,
+{
+    type Output = Exp<Ratio<N, D>, Z0>;
+
+    fn powi(self, _: UTerm) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// (N/D)^U = (N/D)^+U
+impl<N, D, U, B> Pow<UInt<U, B>> for Ratio<N, D>
+    where U: Unsigned,
+          B: Bit,
+          Ratio<N, D>: Pow<PInt<UInt<U, B>>>,
+          Exp<Ratio<N, D>, PInt<UInt<U, B>>>: Default,
+{
+    type Output = Exp<Ratio<N, D>, PInt<UInt<U, B>>>;
+
+    fn powi(self, _: UInt<U, B>) -> Self::Output {
+        Default::default()
+    }
+}
+
+// The orphan rules would allow e.g. `Div<Ratio<N, D>> for PInt<U>`, as for `Gcd` above, but typenum
+// implements the arithmetic operators for `Z0` with any `Integer` on the right, including a whole
+// `Ratio`, so `Z0` could never be covered. `Scalar` puts any `Rational` on the left instead.
+
+/// Computes `x*N/D` if it is an integer which fits in `T`.
+fn mul_primitive<N, D, T>(x: T) -> Option<T>
+    where Ratio<N, D>: Rational,
+          T: Into<i128> + TryFrom<i128>,
+{
+    // The product of any `i64` or `u64` with an `i64` fits in an `i128`.
+    let num = x.into() * i128::from(Num::<N, D>::to_i64());
+    let den = i128::from(Den::<N, D>::to_i64());
+    if num % den == 0 {
+        T::try_from(num / den).ok()
+    } else {
+        None
+    }
+}
+
+// Both orders return the product only if it is a whole number which fits in the type of `x`. Use
+// `Ratio::scale` to get a fractional or out-of-range product as a `DynRatio`.
+macro_rules! impl_mul_primitive {
+    ($($t:ty),*) => {$(
+        /// x * (N/D) = (x*N)/D
+        impl<N, D> Mul<Ratio<N, D>> for $t
+            where Ratio<N, D>: Rational,
+        {
+            type Output = Option<$t>;
+
+            fn mul(self, _: Ratio<N, D>) -> Option<$t> {
+                mul_primitive::<N, D, $t>(self)
+            }
+        }
+
+        /// (N/D) * x = (x*N)/D
+        impl<N, D> Mul<$t> for Ratio<N, D>
+            where Ratio<N, D>: Rational,
+        {
+            type Output = Option<$t>;
+
+            fn mul(self, x: $t) -> Option<$t> {
+                mul_primitive::<N, D, $t>(x)
+            }
+        }
+    )*}
+}
+
+impl_mul_primitive!(i32, i64, u32, u64);
+
+/// The largest magnitude below which every integer is exactly representable as an `f64`.
+const F64_EXACT_INT: u64 = 1 << 53;
+
+/// Computes `x*n/d`, correctly rounded in most cases when `n` and `d` are exactly representable.
+///
+/// The product is split into `hi + lo` with a fused multiply-add, and the quotient of `hi` is then
+/// corrected by the exact remainder of the division and the low part of the product. The
+/// correction itself is rounded, so the result can still be an ulp away from the exact quotient.
+fn mul_div_f64(x: f64, n: i64, d: i64) -> f64 {
+    if n.unsigned_abs() > F64_EXACT_INT || d.unsigned_abs() > F64_EXACT_INT {
+        return x * (n as f64 / d as f64);
+    }
+
+    let (n, d) = (n as f64, d as f64);
+    let hi = x * n;
+    if d == 0.0 {
+        return hi / d;
+    } else if !hi.is_finite() {
+        return x / d * n;
+    }
+
+    let lo = x.mul_add(n, -hi);
+    let q = hi / d;
+    let r = (-q).mul_add(d, hi);
+    q + (r + lo) / d
+}
+
+/// Computes `n/(d*x)`, correctly rounded in most cases when `n` and `d` are exactly representable.
+///
+/// This is the dual of `mul_div_f64`: the divisor is split into `hi + lo`, and the quotient of `hi`
+/// is corrected by the exact remainder and the low part of the divisor.
+fn div_mul_f64(n: i64, d: i64, x: f64) -> f64 {
+    if n.unsigned_abs() > F64_EXACT_INT || d.unsigned_abs() > F64_EXACT_INT {
+        return (n as f64 / d as f64) / x;
+    }
+
+    let (n, d) = (n as f64, d as f64);
+    let hi = d * x;
+    let q = n / hi;
+    if hi == 0.0 || !hi.is_finite() || !q.is_finite() {
+        return q;
+    }
+
+    let lo = d.mul_add(x, -hi);
+    let r = (-q).mul_add(hi, n);
+    q + (r - q * lo) / hi
+}
+
+// `f32` operands are computed in `f64` and then rounded to `f32`, so their results are rounded twice
+// and may differ from the correctly rounded quotient in the last place.
+macro_rules! impl_float_ops {
+    ($($t:ident => $from_f64:expr),*) => {$(
+        /// x * (N/D) = (x*N)/D
+        impl<N, D> Mul<Ratio<N, D>> for $t
+            where Ratio<N, D>: Rational,
+        {
+            type Output = $t;
+
+            fn mul(self, _: Ratio<N, D>) -> $t {
+                let from_f64: fn(f64) -> $t = $from_f64;
+                from_f64(mul_div_f64(self.into(), Num::<N, D>::to_i64(), Den::<N, D>::to_i64()))
+            }
+        }
+
+        /// x / (N/D) = (x*D)/N
+        impl<N, D> Div<Ratio<N, D>> for $t
+            where Ratio<N, D>: Rational,
+        {
+            type Output = $t;
+
+            fn div(self, _: Ratio<N, D>) -> $t {
+                let from_f64: fn(f64) -> $t = $from_f64;
+                from_f64(mul_div_f64(self.into(), Den::<N, D>::to_i64(), Num::<N, D>::to_i64()))
+            }
+        }
+
+        /// (N/D) * x = (x*N)/D
+        impl<N, D> Mul<$t> for Ratio<N, D>
+            where Ratio<N, D>: Rational,
+        {
+            type Output = $t;
+
+            fn mul(self, x: $t) -> $t {
+                x * self
+            }
+        }
+
+        /// (N/D) / x = N/(D*x)
+        impl<N, D> Div<$t> for Ratio<N, D>
+            where Ratio<N, D>: Rational,
+        {
+            type Output = $t;
+
+            fn div(self, x: $t) -> $t {
+                let from_f64: fn(f64) -> $t = $from_f64;
+                from_f64(div_mul_f64(Num::<N, D>::to_i64(), Den::<N, D>::to_i64(), x.into()))
+            }
+        }
+    )*}
+}
+
+impl_float_ops!(f32 => |y| y as f32, f64 => |y| y);
 
 impl<N, D> fmt::Debug for Ratio<N, D>
     where Ratio<N, D>: Rational,
+          Num<N, D>: PrivateDecimal,
+          Den<N, D>: PrivateDecimal,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self)
     }
 }
 
+/// The numerator and denominator are written in full, even if they exceed the range of `i64`.
 impl<N, D> fmt::Display for Ratio<N, D>
     where Ratio<N, D>: Rational,
+          Num<N, D>: PrivateDecimal,
+          Den<N, D>: PrivateDecimal,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}/{}", Num::<N, D>::to_i64(), Den::<N, D>::to_i64())
+        Num::<N, D>::write_decimal(f)?;
+        write!(f, "/")?;
+        Den::<N, D>::write_decimal(f)
     }
 }
@@ -0,0 +1,137 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::*;
+
+use typenum::{P1, operator_aliases::*};
+
+use super::{Rational, operator_aliases::{Num, Den}};
+use super::ratio::Ratio;
+
+/// A rational number `N/D` which is only reduced when its value is needed.
+///
+/// Every operator on [`Ratio`] reduces its output, which means computing a GCD at each step of a
+/// long expression. For deep compile-time formulas this dominates the work of the trait solver
+/// and can exceed the recursion limit. The operators on `RawRatio` skip reduction entirely, so
+/// intermediate numerators and denominators simply grow. The sign of the denominator is not
+/// normalized either, and dividing by zero is only reported once the result is reduced.
+///
+/// `RawRatio` implements [`Rational`] with the reduced form of `N/D`, so it can be compared or
+/// used anywhere a [`Rational`] is expected. Use [`AsRatio`] to reduce it explicitly to the
+/// canonical `Ratio`, and [`AsRawRatio`] to convert a [`Rational`] to a `RawRatio`.
+///
+/// # Example
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, RawRatio, operator_aliases::*};
+///
+/// type Half = RawRatio<P1, P2>;
+/// type Third = RawRatio<P1, P3>;
+///
+/// type Expr = Prod<Sum<Half, Third>, Diff<Half, Third>>;
+/// assert_eq!(Expr::default(), RawRatio::<P5, P36>::default());
+/// assert_eq!(AsRatio::<Expr>::default(), Ratio::<P5, P36>::default());
+/// ```
+///
+/// [`Ratio`]: ./struct.Ratio.html
+/// [`Rational`]: ./trait.Rational.html
+/// [`AsRatio`]: ./operator_aliases/type.AsRatio.html
+/// [`AsRawRatio`]: ./operator_aliases/type.AsRawRatio.html
+pub struct RawRatio<N, D = P1>(PhantomData<(N, D)>);
+
+impl<N, D> Default for RawRatio<N, D> {
+    fn default() -> Self {
+        RawRatio(PhantomData)
+    }
+}
+
+/// N/D => Reduce<N, D>
+impl<N, D> Rational for RawRatio<N, D>
+    where Ratio<N, D>: Rational,
+{
+    type Num = Num<N, D>;
+    type Den = Den<N, D>;
+}
+
+/// Two raw ratios are equal if their reduced forms are.
+impl<N1, D1, N2, D2> PartialEq<RawRatio<N2, D2>> for RawRatio<N1, D1>
+    where Ratio<N1, D1>: PartialEq<Ratio<N2, D2>>,
+{
+    fn eq(&self, _: &RawRatio<N2, D2>) -> bool {
+        Ratio::<N1, D1>::default() == Ratio::<N2, D2>::default()
+    }
+}
+
+impl<N, D> fmt::Debug for RawRatio<N, D>
+    where Ratio<N, D>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", Ratio::<N, D>::default())
+    }
+}
+
+/// (N1/D1) + (N2/D2) = (N1*D2 + N2*D1)/(D1*D2)
+impl<N1, D1, N2, D2> Add<RawRatio<N2, D2>> for RawRatio<N1, D1>
+    where N1: Mul<D2>,
+          N2: Mul<D1>,
+          D1: Mul<D2>,
+          Prod<N1, D2>: Add<Prod<N2, D1>>,
+{
+    type Output = RawRatio<Sum<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>;
+
+    fn add(self, _: RawRatio<N2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// (N1/D1) - (N2/D2) = (N1*D2 - N2*D1)/(D1*D2)
+impl<N1, D1, N2, D2> Sub<RawRatio<N2, D2>> for RawRatio<N1, D1>
+    where N1: Mul<D2>,
+          N2: Mul<D1>,
+          D1: Mul<D2>,
+          Prod<N1, D2>: Sub<Prod<N2, D1>>,
+{
+    type Output = RawRatio<Diff<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>;
+
+    fn sub(self, _: RawRatio<N2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// (N1/D1) * (N2/D2) = (N1*N2)/(D1*D2)
+impl<N1, D1, N2, D2> Mul<RawRatio<N2, D2>> for RawRatio<N1, D1>
+    where N1: Mul<N2>,
+          D1: Mul<D2>,
+{
+    type Output = RawRatio<Prod<N1, N2>, Prod<D1, D2>>;
+
+    fn mul(self, _: RawRatio<N2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// (N1/D1) / (N2/D2) = (N1*D2)/(D1*N2)
+impl<N1, D1, N2, D2> Div<RawRatio<N2, D2>> for RawRatio<N1, D1>
+    where N1: Mul<D2>,
+          D1: Mul<N2>,
+{
+    type Output = RawRatio<Prod<N1, D2>, Prod<D1, N2>>;
+
+    fn div(self, _: RawRatio<N2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// -(N/D) = (-N)/D
+impl<N, D> Neg for RawRatio<N, D>
+    where N: Neg,
+{
+    type Output = RawRatio<Negate<N>, D>;
+
+    fn neg(self) -> Self::Output {
+        Default::default()
+    }
+}
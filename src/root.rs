@@ -0,0 +1,128 @@
+//! Exact roots of ratios, computed by searching upward from one.
+//!
+//! The search takes `root` steps, so roots of very large integers can exhaust the recursion limit.
+
+use std::ops::{Add, BitAnd, Neg, Rem};
+
+use typenum::{
+    B0, B1,
+    Equal, Greater, Less,
+    NInt, PInt,
+    NonZero,
+    P1, P2, Z0,
+    Unsigned,
+    operator_aliases::*,
+    type_operators::*,
+};
+
+use super::{Ratio, Rational, private::*, type_operators::*};
+use super::operator_aliases::{Num, Den};
+
+impl<Q> PrivateRoot<Q> for Z0 {
+    type Output = Z0;
+}
+
+impl<U, Q> PrivateRoot<Q> for PInt<U>
+    where U: Unsigned + NonZero,
+          P1: Pow<Q>,
+          Exp<P1, Q>: Cmp<PInt<U>>,
+          PInt<U>: PrivateRootSearch<Q, P1, Compare<Exp<P1, Q>, PInt<U>>>,
+{
+    type Output = <PInt<U> as PrivateRootSearch<Q, P1, Compare<Exp<P1, Q>, PInt<U>>>>::Output;
+}
+
+/// Negative integers only have real roots of odd degree.
+impl<U, Q> PrivateRoot<Q> for NInt<U>
+    where U: Unsigned + NonZero,
+          Q: Rem<P2, Output = P1>,
+          PInt<U>: PrivateRoot<Q>,
+          PrivateRootOf<PInt<U>, Q>: Neg,
+{
+    type Output = Negate<PrivateRootOf<PInt<U>, Q>>;
+}
+
+/// K^Q == A
+impl<A, Q, K> PrivateRootSearch<Q, K, Equal> for A {
+    type Output = K;
+}
+
+/// K^Q < A, so try K + 1. There is no implementation for K^Q > A.
+impl<A, Q, K> PrivateRootSearch<Q, K, Less> for A
+    where K: Add<P1>,
+          Sum<K, P1>: Pow<Q>,
+          Exp<Sum<K, P1>, Q>: Cmp<A>,
+          A: PrivateRootSearch<Q, Sum<K, P1>, Compare<Exp<Sum<K, P1>, Q>, A>>,
+{
+    type Output = <A as PrivateRootSearch<Q, Sum<K, P1>, Compare<Exp<Sum<K, P1>, Q>, A>>>::Output;
+}
+
+impl<Q> PrivatePerfectPower<Q> for Z0 {
+    type Output = B1;
+}
+
+impl<U, Q> PrivatePerfectPower<Q> for PInt<U>
+    where U: Unsigned + NonZero,
+          P1: Pow<Q>,
+          Exp<P1, Q>: Cmp<PInt<U>>,
+          PInt<U>: PrivatePowerSearch<Q, P1, Compare<Exp<P1, Q>, PInt<U>>>,
+{
+    type Output = <PInt<U> as PrivatePowerSearch<Q, P1, Compare<Exp<P1, Q>, PInt<U>>>>::Output;
+}
+
+impl<U, Q> PrivatePerfectPower<Q> for NInt<U>
+    where U: Unsigned + NonZero,
+{
+    // Only used for squares, which are never negative.
+    type Output = B0;
+}
+
+impl<A, Q, K> PrivatePowerSearch<Q, K, Equal> for A {
+    type Output = B1;
+}
+
+impl<A, Q, K> PrivatePowerSearch<Q, K, Greater> for A {
+    type Output = B0;
+}
+
+impl<A, Q, K> PrivatePowerSearch<Q, K, Less> for A
+    where K: Add<P1>,
+          Sum<K, P1>: Pow<Q>,
+          Exp<Sum<K, P1>, Q>: Cmp<A>,
+          A: PrivatePowerSearch<Q, Sum<K, P1>, Compare<Exp<Sum<K, P1>, Q>, A>>,
+{
+    type Output = <A as PrivatePowerSearch<Q, Sum<K, P1>, Compare<Exp<Sum<K, P1>, Q>, A>>>::Output;
+}
+
+/// N/D is a square iff N and D are both squares, for reduced N/D
+impl<N, D> PerfectSquare for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: PrivatePerfectPower<P2>,
+          Den<N, D>: PrivatePerfectPower<P2>,
+          <Num<N, D> as PrivatePerfectPower<P2>>::Output:
+              BitAnd<<Den<N, D> as PrivatePerfectPower<P2>>::Output>,
+{
+    type Output = And<
+        <Num<N, D> as PrivatePerfectPower<P2>>::Output,
+        <Den<N, D> as PrivatePerfectPower<P2>>::Output,
+    >;
+}
+
+type Powered<N, D, EN, ED> = Exp<Ratio<N, D>, Num<EN, ED>>;
+type PoweredNum<N, D, EN, ED> = <Powered<N, D, EN, ED> as Rational>::Num;
+type PoweredDen<N, D, EN, ED> = <Powered<N, D, EN, ED> as Rational>::Den;
+
+/// (N/D)^(P/Q) = root_Q(N^P)/root_Q(D^P)
+///
+/// The roots of two coprime integers are themselves coprime, so the result is already reduced.
+impl<N, D, EN, ED> RationalPow<Ratio<EN, ED>> for Ratio<N, D>
+    where Ratio<EN, ED>: Rational,
+          Ratio<N, D>: Pow<Num<EN, ED>>,
+          Powered<N, D, EN, ED>: Rational,
+          PoweredNum<N, D, EN, ED>: PrivateRoot<Den<EN, ED>>,
+          PoweredDen<N, D, EN, ED>: PrivateRoot<Den<EN, ED>>,
+{
+    type Output = Ratio<
+        PrivateRootOf<PoweredNum<N, D, EN, ED>, Den<EN, ED>>,
+        PrivateRootOf<PoweredDen<N, D, EN, ED>, Den<EN, ED>>,
+    >;
+}
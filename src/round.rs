@@ -0,0 +1,199 @@
+//! Rounding of ratios to integers and to other ratios.
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use typenum::{
+    B0, B1,
+    N1, P1, P2, P4, P10, Z0,
+    NInt, PInt,
+    NonZero,
+    Unsigned,
+    operator_aliases::*,
+    type_operators::*,
+};
+
+use super::{Ratio, Rational, private::*, type_operators::*};
+use super::rounding::{AwayFromZero, HalfEven, HalfUp, TowardZero};
+use super::operator_aliases::{Num, Den, DivEuclid, Floor, LogFloor, Reduce, Round};
+
+impl PrivateSignum for Z0 {
+    type Output = Z0;
+}
+
+impl<U: Unsigned + NonZero> PrivateSignum for PInt<U> {
+    type Output = P1;
+}
+
+impl<U: Unsigned + NonZero> PrivateSignum for NInt<U> {
+    type Output = N1;
+}
+
+/// round(N/D) = trunc((2*N + sign(N)*D) / (2*D))
+impl<N, D> PrivateRoundHalfAway<D> for N
+    where N: PrivateSignum,
+          P2: Mul<N> + Mul<D>,
+          PrivateSign<N>: Mul<D>,
+          Prod<P2, N>: Add<Prod<PrivateSign<N>, D>>,
+          Sum<Prod<P2, N>, Prod<PrivateSign<N>, D>>: Div<Prod<P2, D>>,
+{
+    type Output = Quot<Sum<Prod<P2, N>, Prod<PrivateSign<N>, D>>, Prod<P2, D>>;
+}
+
+// The Euclidean remainder of N/D, which lies in [0, D) for D > 0.
+type EuclidRem<N, D> = Mod<Sum<Mod<N, D>, D>, D>;
+type FloorQuot<N, D> = Quot<Diff<N, EuclidRem<N, D>>, D>;
+type CeilNum<N, D> = Sum<N, Diff<D, P1>>;
+
+/// floor(N/D) = (N - (N mod D))/D
+impl<N, D> IntegerFloor for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: Rem<Den<N, D>>,
+          Mod<Num<N, D>, Den<N, D>>: Add<Den<N, D>>,
+          Sum<Mod<Num<N, D>, Den<N, D>>, Den<N, D>>: Rem<Den<N, D>>,
+          Num<N, D>: Sub<EuclidRem<Num<N, D>, Den<N, D>>>,
+          Diff<Num<N, D>, EuclidRem<Num<N, D>, Den<N, D>>>: Div<Den<N, D>>,
+{
+    type Output = FloorQuot<Num<N, D>, Den<N, D>>;
+}
+
+/// ceil(N/D) = floor((N + D - 1)/D)
+impl<N, D> IntegerCeil for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Den<N, D>: Sub<P1>,
+          Num<N, D>: Add<Diff<Den<N, D>, P1>>,
+          CeilNum<Num<N, D>, Den<N, D>>: Rem<Den<N, D>>,
+          Mod<CeilNum<Num<N, D>, Den<N, D>>, Den<N, D>>: Add<Den<N, D>>,
+          Sum<Mod<CeilNum<Num<N, D>, Den<N, D>>, Den<N, D>>, Den<N, D>>: Rem<Den<N, D>>,
+          CeilNum<Num<N, D>, Den<N, D>>: Sub<EuclidRem<CeilNum<Num<N, D>, Den<N, D>>, Den<N, D>>>,
+          Diff<CeilNum<Num<N, D>, Den<N, D>>, EuclidRem<CeilNum<Num<N, D>, Den<N, D>>, Den<N, D>>>:
+              Div<Den<N, D>>,
+{
+    type Output = FloorQuot<CeilNum<Num<N, D>, Den<N, D>>, Den<N, D>>;
+}
+
+/// round(N/2) = 2*floor((N + 1)/4) for odd N
+impl<N> PrivateRoundHalfEven<P2, B1> for N
+    where N: Add<P1>,
+          Sum<N, P1>: Rem<P4>,
+          Mod<Sum<N, P1>, P4>: Add<P4>,
+          Sum<Mod<Sum<N, P1>, P4>, P4>: Rem<P4>,
+          Sum<N, P1>: Sub<EuclidRem<Sum<N, P1>, P4>>,
+          Diff<Sum<N, P1>, EuclidRem<Sum<N, P1>, P4>>: Div<P4>,
+          P2: Mul<FloorQuot<Sum<N, P1>, P4>>,
+{
+    type Output = Prod<P2, FloorQuot<Sum<N, P1>, P4>>;
+}
+
+/// Without ties, rounding half to even is the same as rounding half away from zero.
+impl<N, D> PrivateRoundHalfEven<D, B0> for N
+    where N: PrivateRoundHalfAway<D>,
+{
+    type Output = <N as PrivateRoundHalfAway<D>>::Output;
+}
+
+/// round(N/D) = trunc(N/D)
+impl<N, D> IntegerRound<TowardZero> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: Div<Den<N, D>>,
+{
+    type Output = Quot<Num<N, D>, Den<N, D>>;
+}
+
+/// round(N/D) = trunc((N + sign(N)*(D - 1))/D)
+impl<N, D> IntegerRound<AwayFromZero> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: PrivateSignum,
+          Den<N, D>: Sub<P1>,
+          PrivateSign<Num<N, D>>: Mul<Diff<Den<N, D>, P1>>,
+          Num<N, D>: Add<Prod<PrivateSign<Num<N, D>>, Diff<Den<N, D>, P1>>>,
+          Sum<Num<N, D>, Prod<PrivateSign<Num<N, D>>, Diff<Den<N, D>, P1>>>: Div<Den<N, D>>,
+{
+    type Output = Quot<Sum<Num<N, D>, Prod<PrivateSign<Num<N, D>>, Diff<Den<N, D>, P1>>>, Den<N, D>>;
+}
+
+impl<N, D> IntegerRound<HalfUp> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: PrivateRoundHalfAway<Den<N, D>>,
+{
+    type Output = <Num<N, D> as PrivateRoundHalfAway<Den<N, D>>>::Output;
+}
+
+impl<N, D> IntegerRound<HalfEven> for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Den<N, D>: IsEqual<P2>,
+          Num<N, D>: PrivateRoundHalfEven<Den<N, D>, Eq<Den<N, D>, P2>>,
+{
+    type Output = <Num<N, D> as PrivateRoundHalfEven<Den<N, D>, Eq<Den<N, D>, P2>>>::Output;
+}
+
+/// round(R, Step) = Step * round(R/Step)
+impl<N, D, Step, Mode> NearestMultiple<Step, Mode> for Ratio<N, D>
+    where Ratio<N, D>: Div<Step>,
+          Quot<Ratio<N, D>, Step>: IntegerRound<Mode>,
+          Step: Mul<Round<Quot<Ratio<N, D>, Step>, Mode>>,
+{
+    type Output = Prod<Step, Round<Quot<Ratio<N, D>, Step>, Mode>>;
+}
+
+impl<N, D> IntegerTrunc for Ratio<N, D>
+    where Ratio<N, D>: IntegerRound<TowardZero>,
+{
+    type Output = Round<Ratio<N, D>, TowardZero>;
+}
+
+/// fract(N/D) = N/D - trunc(N/D)
+impl<N, D> FractionalPart for Ratio<N, D>
+    where Ratio<N, D>: Rational + IntegerRound<TowardZero>,
+          Reduce<N, D>: Sub<Round<Ratio<N, D>, TowardZero>>,
+{
+    type Output = Diff<Reduce<N, D>, Round<Ratio<N, D>, TowardZero>>;
+}
+
+type EuclidSign<N, D> = PrivateSign<Num<N, D>>;
+type EuclidScaled<N1, D1, N2, D2> = Prod<Quot<Ratio<N1, D1>, Ratio<N2, D2>>, EuclidSign<N2, D2>>;
+
+/// div_euclid(A, B) = sign(B) * floor(A/|B|)
+impl<N1, D1, N2, D2> EuclideanDivision<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: Div<Ratio<N2, D2>>,
+          Ratio<N2, D2>: Rational,
+          Num<N2, D2>: PrivateSignum,
+          Quot<Ratio<N1, D1>, Ratio<N2, D2>>: Mul<EuclidSign<N2, D2>>,
+          EuclidScaled<N1, D1, N2, D2>: IntegerFloor,
+          Floor<EuclidScaled<N1, D1, N2, D2>>: Mul<EuclidSign<N2, D2>>,
+{
+    type Output = Prod<Floor<EuclidScaled<N1, D1, N2, D2>>, EuclidSign<N2, D2>>;
+}
+
+/// rem_euclid(A, B) = A - B*div_euclid(A, B)
+impl<N1, D1, N2, D2> EuclideanRemainder<Ratio<N2, D2>> for Ratio<N1, D1>
+    where Ratio<N1, D1>: EuclideanDivision<Ratio<N2, D2>>,
+          Ratio<N2, D2>: Mul<DivEuclid<Ratio<N1, D1>, Ratio<N2, D2>>>,
+          Ratio<N1, D1>: Sub<Prod<Ratio<N2, D2>, DivEuclid<Ratio<N1, D1>, Ratio<N2, D2>>>>,
+{
+    type Output = Diff<Ratio<N1, D1>, Prod<Ratio<N2, D2>, DivEuclid<Ratio<N1, D1>, Ratio<N2, D2>>>>;
+}
+
+type AbsRatio<N, D> = Ratio<AbsVal<Num<N, D>>, Den<N, D>>;
+
+// F - 1 - floor(log10(|N/D|))
+type SigExp<N, D, F> = Diff<Diff<F, P1>, LogFloor<AbsRatio<N, D>, P10>>;
+type SigScale<N, D, F> = Exp<Ratio<P10>, SigExp<N, D, F>>;
+type Scaled<N, D, F> = Prod<Ratio<N, D>, SigScale<N, D, F>>;
+type SigRounded<N, D, F> = <<Scaled<N, D, F> as Rational>::Num as PrivateRoundHalfAway<
+    <Scaled<N, D, F> as Rational>::Den
+>>::Output;
+
+/// sigfigs(R, F) = round(R * 10^e) / 10^e where e = F - 1 - floor(log10(|R|))
+impl<N, D, F> SignificantFigures<F> for Ratio<N, D>
+    where Ratio<N, D>: Rational + Mul<SigScale<N, D, F>>,
+          Num<N, D>: Abs,
+          AbsRatio<N, D>: IntegerLog<P10>,
+          F: Sub<P1>,
+          Diff<F, P1>: Sub<LogFloor<AbsRatio<N, D>, P10>>,
+          Ratio<P10>: Pow<SigExp<N, D, F>>,
+          Scaled<N, D, F>: Rational,
+          <Scaled<N, D, F> as Rational>::Num: PrivateRoundHalfAway<<Scaled<N, D, F> as Rational>::Den>,
+          Ratio<SigRounded<N, D, F>>: Div<SigScale<N, D, F>>,
+{
+    type Output = Quot<Ratio<SigRounded<N, D, F>>, SigScale<N, D, F>>;
+}
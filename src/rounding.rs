@@ -0,0 +1,63 @@
+//! Rounding modes for operations which produce integers from ratios.
+//!
+//! Each mode is a marker type which implements [`RoundingMode`], so that it can be selected by a
+//! type parameter. The same types select the mode of the [`Round`] type operator.
+//!
+//! [`RoundingMode`]: ./trait.RoundingMode.html
+//! [`Round`]: ../operator_aliases/type.Round.html
+
+/// A policy for rounding the quotient of two integers.
+pub trait RoundingMode {
+    /// Returns `num/den` rounded according to this mode.
+    ///
+    /// `den` must be positive.
+    fn round_div(num: i128, den: i128) -> i128;
+}
+
+/// Rounds toward zero, discarding any fractional part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TowardZero;
+
+/// Rounds away from zero whenever there is a fractional part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AwayFromZero;
+
+/// Rounds to the nearest integer, with ties rounded away from zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HalfUp;
+
+/// Rounds to the nearest integer, with ties rounded to the nearest even integer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HalfEven;
+
+impl RoundingMode for TowardZero {
+    fn round_div(num: i128, den: i128) -> i128 {
+        num / den
+    }
+}
+
+impl RoundingMode for AwayFromZero {
+    fn round_div(num: i128, den: i128) -> i128 {
+        let q = num / den;
+        if num % den != 0 { q + num.signum() } else { q }
+    }
+}
+
+impl RoundingMode for HalfUp {
+    fn round_div(num: i128, den: i128) -> i128 {
+        let q = num / den;
+        if 2 * (num % den).abs() >= den { q + num.signum() } else { q }
+    }
+}
+
+impl RoundingMode for HalfEven {
+    fn round_div(num: i128, den: i128) -> i128 {
+        let q = num / den;
+        let twice_rem = 2 * (num % den).abs();
+        if twice_rem > den || (twice_rem == den && q % 2 != 0) {
+            q + num.signum()
+        } else {
+            q
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::*;
+
+use typenum::operator_aliases::*;
+
+use super::{Rational, ToRatio, operator_aliases::AsRatio};
+
+/// A [`Rational`] which can appear on the left-hand side of an arithmetic operator.
+///
+/// Typenum's integers do not implement the arithmetic operators with a [`Ratio`] on the
+/// right-hand side, so `P2 / Ratio<P1, P3>` cannot be written directly. This crate could add such
+/// impls for most of them, but not for `Z0`: typenum already implements e.g. `Add<I>` for `Z0`
+/// for every [`typenum::Integer`] `I`, which includes every whole `Ratio`. Nor can it add them for
+/// user-defined [`Rational`]s. Wrapping the left-hand side in a `Scalar` works for all of these:
+/// `Scalar<I>` can be added to, subtracted from, multiplied by, divided by and reduced modulo any
+/// [`Rational`], which produces the same reduced [`Ratio`] as the operation on [`AsRatio<I>`]. `I`
+/// can be any [`Rational`], including [`typenum::Integer`]s and [`typenum::Unsigned`]s.
+///
+/// A `Scalar` is also [`Rational`] itself, with the value of `I`.
+///
+/// # Example
+///
+/// ```
+/// #[macro_use] extern crate typenum_ratio;
+/// extern crate typenum;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, Scalar};
+///
+/// assert_eq!(Scalar::<P2>::new() / rat!(P1/P3), Ratio::<P6>::default());
+/// assert_eq!(Scalar::<P2>::new() - rat!(P1/P2), rat!(P3/P2));
+/// assert_eq!(Scalar::<U3>::new() * Ratio::<P1, P6>::default(), rat!(P1/P2));
+/// ```
+///
+/// [`Rational`]: ./trait.Rational.html
+/// [`Ratio`]: ./struct.Ratio.html
+/// [`AsRatio<I>`]: ./operator_aliases/type.AsRatio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+/// [`typenum::Unsigned`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
+pub struct Scalar<I>(PhantomData<I>);
+
+impl<I> Scalar<I>
+    where I: Rational,
+{
+    /// Constructs a new `Scalar`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<I> Default for Scalar<I> {
+    fn default() -> Self {
+        Scalar(PhantomData)
+    }
+}
+
+impl<I> fmt::Debug for Scalar<I>
+    where I: ToRatio,
+          AsRatio<I>: fmt::Debug + Default,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Scalar({:?})", AsRatio::<I>::default())
+    }
+}
+
+/// I => I::Num/I::Den
+impl<I> Rational for Scalar<I>
+    where I: Rational,
+{
+    type Num = I::Num;
+    type Den = I::Den;
+}
+
+macro_rules! impl_scalar_ops {
+    ($(($op:ident, $fn:ident, $alias:ident)),*) => {$(
+        impl<I, R> $op<R> for Scalar<I>
+            where I: ToRatio,
+                  R: Rational,
+                  AsRatio<I>: $op<R> + Default,
+        {
+            type Output = $alias<AsRatio<I>, R>;
+
+            fn $fn(self, rhs: R) -> Self::Output {
+                AsRatio::<I>::default().$fn(rhs)
+            }
+        }
+    )*}
+}
+
+impl_scalar_ops!(
+    (Add, add, Sum),
+    (Sub, sub, Diff),
+    (Mul, mul, Prod),
+    (Div, div, Quot),
+    (Rem, rem, Mod)
+);
@@ -0,0 +1,101 @@
+//! Enumerations of the positive rationals by binary trees.
+
+use std::ops::{Add, Div, Sub};
+
+use typenum::{
+    B0, B1, Bit,
+    Equal, Greater, Less,
+    P1, Z0,
+    UInt, UTerm,
+    Unsigned,
+    array::{ATerm, TArr},
+    operator_aliases::*,
+    type_operators::*,
+};
+
+use super::{Ratio, Rational, private::*, type_operators::*};
+use super::operator_aliases::{Num, Den, FromSternBrocot};
+
+type CalkinWilfNode<N> = <N as PrivateCalkinWilf>::Output;
+
+type Path<N, D> = <N as PrivateSternBrocot<D, Compare<N, D>>>::Output;
+
+impl<N, D> SternBrocotEncode for Ratio<N, D>
+    where Ratio<N, D>: Rational,
+          Num<N, D>: IsGreater<Z0, Output = B1> + Cmp<Den<N, D>>,
+          Num<N, D>: PrivateSternBrocot<Den<N, D>, Compare<Num<N, D>, Den<N, D>>>,
+{
+    type Output = Path<Num<N, D>, Den<N, D>>;
+}
+
+/// P/Q == 1 is the root of the tree.
+impl<P, Q> PrivateSternBrocot<Q, Equal> for P {
+    type Output = ATerm;
+}
+
+/// P/Q > 1 is in the right subtree, which is the left subtree shifted up by one.
+impl<P, Q> PrivateSternBrocot<Q, Greater> for P
+    where P: Sub<Q>,
+          Diff<P, Q>: Cmp<Q> + PrivateSternBrocot<Q, Compare<Diff<P, Q>, Q>>,
+{
+    type Output = TArr<B1, Path<Diff<P, Q>, Q>>;
+}
+
+/// P/Q < 1 is in the left subtree, which maps x to x/(1 + x).
+impl<P, Q> PrivateSternBrocot<Q, Less> for P
+    where Q: Sub<P>,
+          P: Cmp<Diff<Q, P>> + PrivateSternBrocot<Diff<Q, P>, Compare<P, Diff<Q, P>>>,
+{
+    type Output = TArr<B0, Path<P, Diff<Q, P>>>;
+}
+
+impl SternBrocotDecode for ATerm {
+    type Output = Ratio<P1>;
+}
+
+impl<Rest> SternBrocotDecode for TArr<B1, Rest>
+    where Rest: SternBrocotDecode,
+          FromSternBrocot<Rest>: Add<P1>,
+{
+    type Output = Sum<FromSternBrocot<Rest>, P1>;
+}
+
+impl<Rest> SternBrocotDecode for TArr<B0, Rest>
+    where Rest: SternBrocotDecode,
+          FromSternBrocot<Rest>: Add<P1>,
+          FromSternBrocot<Rest>: Div<Sum<FromSternBrocot<Rest>, P1>>,
+{
+    type Output = Quot<FromSternBrocot<Rest>, Sum<FromSternBrocot<Rest>, P1>>;
+}
+
+/// The `U`-th term of the Calkin–Wilf sequence is node `U + 1` of the Calkin–Wilf tree.
+impl<U> CalkinWilf for U
+    where U: Unsigned + Add<B1>,
+          Add1<U>: PrivateCalkinWilf,
+{
+    type Output = CalkinWilfNode<Add1<U>>;
+}
+
+/// Node 1 is the root, 1/1.
+impl PrivateCalkinWilf for UInt<UTerm, B1> {
+    type Output = Ratio<P1>;
+}
+
+/// Node 2n is the left child of node n, which maps x to x/(1 + x).
+impl<U, B> PrivateCalkinWilf for UInt<UInt<U, B>, B0>
+    where B: Bit,
+          UInt<U, B>: PrivateCalkinWilf,
+          CalkinWilfNode<UInt<U, B>>: Add<P1>,
+          CalkinWilfNode<UInt<U, B>>: Div<Sum<CalkinWilfNode<UInt<U, B>>, P1>>,
+{
+    type Output = Quot<CalkinWilfNode<UInt<U, B>>, Sum<CalkinWilfNode<UInt<U, B>>, P1>>;
+}
+
+/// Node 2n + 1 is the right child of node n, which maps x to x + 1.
+impl<U, B> PrivateCalkinWilf for UInt<UInt<U, B>, B1>
+    where B: Bit,
+          UInt<U, B>: PrivateCalkinWilf,
+          CalkinWilfNode<UInt<U, B>>: Add<P1>,
+{
+    type Output = Sum<CalkinWilfNode<UInt<U, B>>, P1>;
+}
@@ -0,0 +1,569 @@
+//! Type operators on rational numbers which have no equivalent in `std::ops` or `typenum`.
+//!
+//! Each trait here has a corresponding alias in [`operator_aliases`] which should be preferred
+//! when naming the result of an operation.
+//!
+//! [`operator_aliases`]: ../operator_aliases/index.html
+
+/// The least common multiple of `Self` and `Rhs`.
+///
+/// For two reduced fractions, this is the least common multiple of the numerators over the
+/// greatest common divisor of the denominators. It is the smallest positive ratio of which both
+/// `Self` and `Rhs` are integer multiples, and is implemented for a non-zero [`Ratio`] and any
+/// non-zero [`Rational`], including [`typenum::Integer`]s and [`typenum::Unsigned`]s, in either
+/// order.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`Rational`]: ../trait.Rational.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+/// [`typenum::Unsigned`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
+pub trait Lcm<Rhs = Self> {
+    /// The least common multiple.
+    type Output;
+}
+
+/// `Self` raised to the rational power `E`.
+///
+/// This is only implemented when the result is itself rational, i.e. when the numerator and
+/// denominator of `Self^p` are both perfect `q`-th powers, where `E = p/q`.
+pub trait RationalPow<E> {
+    /// The result of the exponentiation.
+    type Output;
+}
+
+/// The determinant of the matrix `Self`.
+///
+/// This is implemented for [`RatMat2`].
+///
+/// [`RatMat2`]: ../struct.RatMat2.html
+pub trait MatrixDeterminant {
+    /// The determinant.
+    type Output;
+}
+
+/// The result of applying the Möbius transformation `Self` to `X`.
+///
+/// This is implemented for [`Mobius`] and any [`Ratio`] which is not a pole of the
+/// transformation.
+///
+/// [`Mobius`]: ../type.Mobius.html
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait MobiusTransform<X> {
+    /// The transformed value.
+    type Output;
+}
+
+/// The intersection of the intervals `Self` and `Rhs`.
+///
+/// This is implemented for pairs of [`RatInterval`]s which overlap.
+///
+/// [`RatInterval`]: ../struct.RatInterval.html
+pub trait IntervalIntersection<Rhs> {
+    /// The intersection.
+    type Output;
+}
+
+/// Whether the interval `Self` contains `R`, as a [`typenum::Bit`].
+///
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait IntervalContains<R> {
+    /// `B1` if `R` lies in `Self`, `B0` otherwise.
+    type Output;
+}
+
+/// Asserts that `Self` and `Rhs` are equal ratios, even if they are not the same type.
+///
+/// This is the analogue of [`typenum::Same`] for ratios: it is implemented only if the reduced
+/// forms of `Self` and `Rhs` are identical, so `Ratio<P1, P2>: SameRatio<Ratio<P2, P4>>` holds even
+/// though `Ratio<P1, P2>: Same<Ratio<P2, P4>>` does not.
+///
+/// # Example
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, type_operators::SameRatio};
+///
+/// /// Requires the sample rate `R`, in kHz, to be exactly 48 kHz.
+/// fn configure<R: SameRatio<Ratio<P48>>>() {}
+///
+/// configure::<Ratio<P96, P2>>();
+/// ```
+///
+/// ```compile_fail
+/// # extern crate typenum;
+/// # extern crate typenum_ratio;
+/// # use typenum::consts::*;
+/// # use typenum_ratio::{Ratio, type_operators::SameRatio};
+/// fn configure<R: SameRatio<Ratio<P48>>>() {}
+///
+/// configure::<Ratio<P441, P10>>();
+/// ```
+///
+/// [`typenum::Same`]: https://docs.rs/typenum/1.10.0/typenum/type_operators/trait.Same.html
+pub trait SameRatio<Rhs> {
+    /// The reduced form of `Self`.
+    type Output;
+}
+
+/// The sign of `Self`: `N1` if it is negative, `Z0` if it is zero and `P1` if it is positive.
+///
+/// This is implemented for every [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait RatioSignum {
+    /// The sign, as a [`typenum::Integer`].
+    ///
+    /// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+    type Output;
+}
+
+/// Whether `Self` is an integer, i.e. whether its reduced denominator is one, as a
+/// [`typenum::Bit`].
+///
+/// This is implemented for every [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait IntegralRatio {
+    /// `B1` if `Self` is an integer, `B0` otherwise.
+    type Output;
+}
+
+/// Converts a whole rational number to the [`typenum::Integer`] equal to it.
+///
+/// This is implemented for every [`Rational`] whose reduced denominator is one.
+///
+/// [`Rational`]: ../trait.Rational.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait WholeRatio {
+    /// The integer equal to `Self`.
+    type Output;
+}
+
+/// Whether `Self` is a dyadic rational, i.e. whether its reduced denominator is a power of two,
+/// as a [`typenum::Bit`].
+///
+/// These are exactly the ratios which have a finite binary expansion. This is implemented for
+/// every [`Ratio`]; see also [`ExactlyRepresentable`].
+///
+/// [`ExactlyRepresentable`]: ./trait.ExactlyRepresentable.html
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait DyadicRatio {
+    /// `B1` if `Self` is dyadic, `B0` otherwise.
+    type Output;
+}
+
+/// Whether `Self` has a terminating decimal expansion, i.e. whether its reduced denominator is
+/// of the form `2^a * 5^b`, as a [`typenum::Bit`].
+///
+/// This is implemented for every [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait TerminatingDecimal {
+    /// `B1` if the decimal expansion of `Self` terminates, `B0` otherwise.
+    type Output;
+}
+
+/// Whether `Self` is the square of a rational number, as a [`typenum::Bit`].
+///
+/// This is implemented for every [`Ratio`]. If the result is `B1`, the square root is given by
+/// [`PowRatio`] with an exponent of `1/2`.
+///
+/// [`PowRatio`]: ../operator_aliases/type.PowRatio.html
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait PerfectSquare {
+    /// `B1` if `Self` is a perfect square, `B0` otherwise.
+    type Output;
+}
+
+/// The combined value of two components in parallel, `1/(1/Self + 1/Rhs)`.
+///
+/// This is the equivalent resistance of two resistors (or inductance of two inductors) connected
+/// in parallel.
+pub trait CombineParallel<Rhs = Self> {
+    /// The result of the combination.
+    type Output;
+}
+
+/// The transfer ratio of a voltage divider, `Self/(Self + Rhs)`.
+///
+/// `Self` is the component across which the output is measured.
+pub trait DividerRatio<Rhs = Self> {
+    /// The transfer ratio of the divider.
+    type Output;
+}
+
+/// The component which, combined with `Self` in a voltage divider, gives a transfer ratio of
+/// `Target`.
+///
+/// This is the inverse of [`DividerRatio`]: `Self*(1 - Target)/Target`.
+///
+/// [`DividerRatio`]: ./trait.DividerRatio.html
+pub trait DividerSolve<Target> {
+    /// The value of the other component.
+    type Output;
+}
+
+/// `Self` clamped to the closed interval `[Lo, Hi]`.
+///
+/// The result is one of `Self`, `Lo` or `Hi`. This is implemented for every [`Ratio`] as long as
+/// `Lo <= Hi`.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait ClampTo<Lo, Hi> {
+    /// The clamped value.
+    type Output;
+}
+
+/// The reciprocal of `Self`, `1/Self`.
+///
+/// This is implemented for every non-zero [`Ratio`] and every non-singular [`RatMat2`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`RatMat2`]: ../struct.RatMat2.html
+pub trait Inv {
+    /// The reciprocal.
+    type Output;
+}
+
+/// The coefficients of the Taylor series of `exp(x)` up to order `Self`.
+///
+/// This is the type-level list `tarr![1/0!, 1/1!, ..., 1/Self!]` of reduced [`Ratio`]s, and is
+/// implemented for every [`typenum::Unsigned`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Unsigned`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
+pub trait TaylorCoefficients {
+    /// The list of coefficients.
+    type Output;
+}
+
+/// The `Self`-th convergent of the golden ratio, `F(Self + 1)/F(Self)`, where `F(n)` is the `n`-th
+/// Fibonacci number.
+///
+/// This is implemented for every [`typenum::Unsigned`], but the result is only a valid
+/// [`Rational`] for `Self > 0`.
+///
+/// [`Rational`]: ../trait.Rational.html
+/// [`typenum::Unsigned`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
+pub trait FibonacciRatio {
+    /// The convergent.
+    type Output;
+}
+
+/// The coefficients `[a0; a1, ..., an]` of the simple continued fraction of `Self`, as a
+/// type-level list of [`typenum::Integer`]s.
+///
+/// The first coefficient is `floor(Self)` and may be zero or negative. Every subsequent coefficient
+/// is positive, and the last coefficient is greater than one unless the list has a single element.
+/// This is implemented for every [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait ContinuedFractionExpansion {
+    /// The list of coefficients.
+    type Output;
+}
+
+/// The value of the simple continued fraction whose coefficients are the type-level list `Self`.
+///
+/// This is the inverse of [`ContinuedFractionExpansion`], and is implemented for every non-empty
+/// list of [`typenum::Integer`]s whose partial evaluations are non-zero.
+///
+/// [`ContinuedFractionExpansion`]: ./trait.ContinuedFractionExpansion.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait ContinuedFractionValue {
+    /// The reduced value.
+    type Output;
+}
+
+/// The path from the root of the [Stern–Brocot tree][sb] to `Self`, as a type-level list of
+/// [`typenum::Bit`]s.
+///
+/// Each `B0` is a step into the left subtree and each `B1` a step into the right subtree. The
+/// root, `1`, has an empty path. This is implemented for every positive [`Ratio`].
+///
+/// [sb]: https://en.wikipedia.org/wiki/Stern%E2%80%93Brocot_tree
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait SternBrocotEncode {
+    /// The path.
+    type Output;
+}
+
+/// The ratio at the end of the path `Self` in the Stern–Brocot tree.
+///
+/// This is the inverse of [`SternBrocotEncode`], and is implemented for every type-level list of
+/// [`typenum::Bit`]s.
+///
+/// [`SternBrocotEncode`]: ./trait.SternBrocotEncode.html
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait SternBrocotDecode {
+    /// The reduced ratio.
+    type Output;
+}
+
+/// The `Self`-th term of the [Calkin–Wilf sequence][cw], counting from zero.
+///
+/// The sequence `1, 1/2, 2, 1/3, 3/2, 2/3, 3, ...` contains every positive rational number exactly
+/// once. This is implemented for every [`typenum::Unsigned`].
+///
+/// [cw]: https://en.wikipedia.org/wiki/Calkin%E2%80%93Wilf_tree
+/// [`typenum::Unsigned`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
+pub trait CalkinWilf {
+    /// The reduced ratio.
+    type Output;
+}
+
+/// The type-level list of the first `Len` terms of the arithmetic sequence starting at `Self` with
+/// a common difference of `Step`.
+pub trait ArithmeticSequence<Step, Len> {
+    /// The list of terms.
+    type Output;
+}
+
+/// The type-level list of the first `Len` terms of the geometric sequence starting at `Self` with
+/// a common ratio of `Factor`.
+pub trait GeometricSequence<Factor, Len> {
+    /// The list of terms.
+    type Output;
+}
+
+/// The sum of every [`Ratio`] in the type-level list `Self`.
+///
+/// The sum of the empty list is zero.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait SumList {
+    /// The reduced sum.
+    type Output;
+}
+
+/// The product of every [`Ratio`] in the type-level list `Self`.
+///
+/// The product of the empty list is one.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait ProductList {
+    /// The reduced product.
+    type Output;
+}
+
+/// The sum of the pairwise products of the type-level lists of [`Ratio`]s `Self` and `Rhs`.
+///
+/// This is only implemented if both lists have the same length. The inner product of two empty
+/// lists is zero.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait InnerProduct<Rhs> {
+    /// The inner product.
+    type Output;
+}
+
+/// The type-level list `Self` of [`Ratio`]s, sorted in ascending order.
+///
+/// The sort is stable, and the elements themselves are left unreduced.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait SortList {
+    /// The sorted list.
+    type Output;
+}
+
+/// The least [`Ratio`] in the non-empty type-level list `Self`.
+///
+/// The result is the element itself, unreduced. If several elements are equal to the minimum, the
+/// first of them is chosen.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait MinimumList {
+    /// The least element.
+    type Output;
+}
+
+/// The greatest [`Ratio`] in the non-empty type-level list `Self`.
+///
+/// The result is the element itself, unreduced. If several elements are equal to the maximum, the
+/// first of them is chosen.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait MaximumList {
+    /// The greatest element.
+    type Output;
+}
+
+/// The [`Ratio`] in the non-empty type-level list `Self` which is nearest to `X`.
+///
+/// The result is the element itself, unreduced. If several elements are equally near to `X`, the
+/// first of them is chosen.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait NearestInList<X> {
+    /// The nearest element.
+    type Output;
+}
+
+/// The floor of the logarithm of `Self` in base `B`, as a [`typenum::Integer`].
+///
+/// This is implemented for every positive [`Ratio`] and every integer base `B >= 2`.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait IntegerLog<B> {
+    /// The logarithm, rounded toward negative infinity.
+    type Output;
+}
+
+/// Whether `Self` is `B^k` for some integer `k`, as a [`typenum::Bit`].
+///
+/// This is implemented for every [`Ratio`] and every integer base `B >= 2`.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait PowerOfBase<B> {
+    /// `B1` if `Self` is a power of `B`, `B0` otherwise.
+    type Output;
+}
+
+/// The integer `k` such that `B^k == Self`, as a [`typenum::Integer`].
+///
+/// This is only implemented if `Self` is a power of `B`; see [`PowerOfBase`].
+///
+/// [`PowerOfBase`]: ./trait.PowerOfBase.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait ExactLog<B> {
+    /// The logarithm.
+    type Output;
+}
+
+/// The smallest integer `k` such that `2^k >= Self`, as a [`typenum::Integer`].
+///
+/// This is implemented for every positive [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait CeilLog2 {
+    /// The logarithm, rounded toward positive infinity.
+    type Output;
+}
+
+/// The greatest integer less than or equal to `Self`, as a [`typenum::Integer`].
+///
+/// This is implemented for every [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait IntegerFloor {
+    /// The ratio, rounded toward negative infinity.
+    type Output;
+}
+
+/// The least integer greater than or equal to `Self`, as a [`typenum::Integer`].
+///
+/// This is implemented for every [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait IntegerCeil {
+    /// The ratio, rounded toward positive infinity.
+    type Output;
+}
+
+/// `Self` rounded to an integer according to the rounding mode `Mode`, as a
+/// [`typenum::Integer`].
+///
+/// This is implemented for every [`Ratio`] with each of the modes in the [`rounding`] module.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`rounding`]: ../rounding/index.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait IntegerRound<Mode> {
+    /// The rounded ratio.
+    type Output;
+}
+
+/// `Self` rounded to an integer multiple of `Step` according to the rounding mode `Mode`.
+///
+/// This is implemented for every [`Ratio`] and non-zero [`Ratio`] `Step`, with each of the modes
+/// in the [`rounding`] module.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`rounding`]: ../rounding/index.html
+pub trait NearestMultiple<Step, Mode> {
+    /// The rounded ratio.
+    type Output;
+}
+
+/// The integer part of `Self`, rounded toward zero, as a [`typenum::Integer`].
+///
+/// This is implemented for every [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait IntegerTrunc {
+    /// The integer part.
+    type Output;
+}
+
+/// The fractional part of `Self`, `Self - trunc(Self)`, as a [`Ratio`].
+///
+/// The result has the same sign as `Self`, and is implemented for every [`Ratio`].
+///
+/// [`Ratio`]: ../struct.Ratio.html
+pub trait FractionalPart {
+    /// The fractional part.
+    type Output;
+}
+
+/// The quotient of Euclidean division of `Self` by `Rhs`, as a [`typenum::Integer`].
+///
+/// This is the integer `q` such that `Self = Rhs*q + r` with `0 <= r < |Rhs|`, and is implemented
+/// for every [`Ratio`] and every non-zero [`Ratio`] `Rhs`.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait EuclideanDivision<Rhs> {
+    /// The quotient.
+    type Output;
+}
+
+/// The remainder of Euclidean division of `Self` by `Rhs`, which is never negative.
+///
+/// See [`EuclideanDivision`] for details.
+///
+/// [`EuclideanDivision`]: ./trait.EuclideanDivision.html
+pub trait EuclideanRemainder<Rhs> {
+    /// The remainder.
+    type Output;
+}
+
+/// `Self` rounded to `F` significant decimal digits, with ties rounded away from zero.
+///
+/// This is implemented for every non-zero [`Ratio`] and every positive [`typenum::Integer`] `F`.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Integer`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Integer.html
+pub trait SignificantFigures<F> {
+    /// The rounded ratio.
+    type Output;
+}
+
+/// Whether `Self` is exactly representable as a value of the floating-point type `F`, as a
+/// [`typenum::Bit`].
+///
+/// This is implemented for every [`Ratio`] with `F` either `f32` or `f64`. A ratio is exactly
+/// representable if its denominator is a power of two and its value is within the precision and
+/// range (including subnormals) of `F`.
+///
+/// [`Ratio`]: ../struct.Ratio.html
+/// [`typenum::Bit`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Bit.html
+pub trait ExactlyRepresentable<F> {
+    /// `B1` if `Self` is exactly representable, `B0` otherwise.
+    type Output;
+}
@@ -0,0 +1,243 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::*;
+
+use typenum::{
+    B0, B1, Bit,
+    Integer,
+    NonZero,
+    PInt, Z0,
+    U1, UInt, UTerm,
+    Unsigned,
+    operator_aliases::*,
+    type_operators::*,
+};
+
+use super::{Rational, ToURatio, UnsignedRational, private::*};
+
+/// A non-negative rational number whose numerator and denominator are [`typenum::Unsigned`]s.
+///
+/// This is the unsigned counterpart of [`Ratio`], for quantities such as scale factors which can
+/// never be negative. It implements [`UnsignedRational`] with the reduced form of `N/D`, which only
+/// requires `D` to be [`NonZero`]. Skipping the sign handling of `Ratio` makes reduction cheaper for
+/// the trait solver and keeps the bounds of generic code shorter.
+///
+/// `URatio`s can be added, subtracted, multiplied and divided by one another, which always produces
+/// a reduced `URatio`. Subtraction only compiles if the result is non-negative. A `URatio` also
+/// implements [`Rational`], so it can be used anywhere a signed ratio is expected; see
+/// [`AsURatio`] for the reverse conversion.
+///
+/// # Example
+///
+/// ```
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, URatio, operator_aliases::*};
+///
+/// type Upsample = URatio<U3, U2>;
+/// type Downsample = URatio<U1, U4>;
+///
+/// assert_eq!(Prod::<Upsample, Downsample>::default(), URatio::<U3, U8>::default());
+/// assert_eq!(Diff::<Upsample, Downsample>::default(), URatio::<U5, U4>::default());
+/// assert_eq!(AsRatio::<Upsample>::default(), Ratio::<P3, P2>::default());
+/// assert_eq!(AsURatio::<Ratio<N3, N6>>::default(), URatio::<U1, U2>::default());
+/// ```
+///
+/// [`typenum::Unsigned`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
+/// [`NonZero`]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.NonZero.html
+/// [`Ratio`]: ./struct.Ratio.html
+/// [`Rational`]: ./trait.Rational.html
+/// [`UnsignedRational`]: ./trait.UnsignedRational.html
+/// [`AsURatio`]: ./operator_aliases/type.AsURatio.html
+pub struct URatio<N, D = U1>(PhantomData<(N, D)>);
+
+type UNum<N, D> = <URatio<N, D> as UnsignedRational>::Num;
+type UDen<N, D> = <URatio<N, D> as UnsignedRational>::Den;
+type UReduce<N, D> = URatio<UNum<N, D>, UDen<N, D>>;
+
+impl<N, D> Default for URatio<N, D> {
+    fn default() -> Self {
+        URatio(PhantomData)
+    }
+}
+
+/// N/D => (N/gcd)/(D/gcd)
+///
+/// The GCD is only computed when it is not trivially one and after removing the factors of two
+/// common to `N` and `D`, which only requires looking at their last bits.
+impl<N, D> UnsignedRational for URatio<N, D>
+    where N: PrivateUReduce<D>,
+{
+    type Num = <N as PrivateUReduce<D>>::Num;
+    type Den = <N as PrivateUReduce<D>>::Den;
+}
+
+/// 0/D => 0/1
+impl<D> PrivateUReduce<D> for UTerm
+    where D: Unsigned + NonZero,
+{
+    type Num = UTerm;
+    type Den = U1;
+}
+
+/// N/1 => N/1
+impl<U: Unsigned, B: Bit> PrivateUReduce<U1> for UInt<U, B> {
+    type Num = UInt<U, B>;
+    type Den = U1;
+}
+
+/// 1/D => 1/D
+impl<V: Unsigned, C: Bit, E: Bit> PrivateUReduce<UInt<UInt<V, C>, E>> for U1 {
+    type Num = U1;
+    type Den = UInt<UInt<V, C>, E>;
+}
+
+/// 2N/2D => N/D
+impl<U, V, C> PrivateUReduce<UInt<UInt<V, C>, B0>> for UInt<U, B0>
+    where U: PrivateUReduce<UInt<V, C>>,
+{
+    type Num = <U as PrivateUReduce<UInt<V, C>>>::Num;
+    type Den = <U as PrivateUReduce<UInt<V, C>>>::Den;
+}
+
+/// N/D for odd N > 1
+impl<W, F, V, C, E> PrivateUReduce<UInt<UInt<V, C>, E>> for UInt<UInt<W, F>, B1>
+    where UInt<UInt<W, F>, B1>: PrivateGcdReduce<UInt<UInt<V, C>, E>>,
+{
+    type Num = <UInt<UInt<W, F>, B1> as PrivateGcdReduce<UInt<UInt<V, C>, E>>>::Num;
+    type Den = <UInt<UInt<W, F>, B1> as PrivateGcdReduce<UInt<UInt<V, C>, E>>>::Den;
+}
+
+/// 2N/D for odd D > 1
+impl<U, V, C> PrivateUReduce<UInt<UInt<V, C>, B1>> for UInt<U, B0>
+    where UInt<U, B0>: PrivateGcdReduce<UInt<UInt<V, C>, B1>>,
+{
+    type Num = <UInt<U, B0> as PrivateGcdReduce<UInt<UInt<V, C>, B1>>>::Num;
+    type Den = <UInt<U, B0> as PrivateGcdReduce<UInt<UInt<V, C>, B1>>>::Den;
+}
+
+impl<N, D> PrivateGcdReduce<D> for N
+    where N: Unsigned + Gcd<D> + Div<Gcf<N, D>>,
+          D: Unsigned + NonZero + Div<Gcf<N, D>>,
+          Quot<N, Gcf<N, D>>: Unsigned,
+          Quot<D, Gcf<N, D>>: Unsigned + NonZero,
+{
+    type Num = Quot<N, Gcf<N, D>>;
+    type Den = Quot<D, Gcf<N, D>>;
+}
+
+impl PrivateSigned for UTerm {
+    type Output = Z0;
+}
+
+impl<U: Unsigned, B: Bit> PrivateSigned for UInt<U, B> {
+    type Output = PInt<UInt<U, B>>;
+}
+
+impl PrivateUnsigned for Z0 {
+    type Output = UTerm;
+}
+
+impl<U: Unsigned + NonZero> PrivateUnsigned for PInt<U> {
+    type Output = U;
+}
+
+/// N/D => +N/+D
+impl<N, D> Rational for URatio<N, D>
+    where URatio<N, D>: UnsignedRational,
+          UNum<N, D>: PrivateSigned,
+          UDen<N, D>: PrivateSigned,
+          <UNum<N, D> as PrivateSigned>::Output: Integer,
+          <UDen<N, D> as PrivateSigned>::Output: Integer + NonZero,
+{
+    type Num = <UNum<N, D> as PrivateSigned>::Output;
+    type Den = <UDen<N, D> as PrivateSigned>::Output;
+}
+
+/// Only non-negative ratios have an unsigned equivalent.
+impl<R> ToURatio for R
+    where R: Rational,
+          R::Num: PrivateUnsigned,
+          R::Den: PrivateUnsigned,
+          URatio<<R::Num as PrivateUnsigned>::Output, <R::Den as PrivateUnsigned>::Output>:
+              UnsignedRational,
+{
+    type Output = URatio<<R::Num as PrivateUnsigned>::Output, <R::Den as PrivateUnsigned>::Output>;
+}
+
+impl<N1, D1, N2, D2> PartialEq<URatio<N2, D2>> for URatio<N1, D1>
+    where URatio<N1, D1>: UnsignedRational,
+          URatio<N2, D2>: UnsignedRational,
+          UNum<N1, D1>: IsEqual<UNum<N2, D2>>,
+          UDen<N1, D1>: IsEqual<UDen<N2, D2>>,
+{
+    fn eq(&self, _: &URatio<N2, D2>) -> bool {
+        Eq::<UNum<N1, D1>, UNum<N2, D2>>::to_bool() && Eq::<UDen<N1, D1>, UDen<N2, D2>>::to_bool()
+    }
+}
+
+impl<N, D> fmt::Debug for URatio<N, D>
+    where URatio<N, D>: UnsignedRational,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", UNum::<N, D>::to_u64(), UDen::<N, D>::to_u64())
+    }
+}
+
+/// (N1/D1) + (N2/D2) = (N1*D2 + N2*D1)/(D1*D2)
+impl<N1, D1, N2, D2> Add<URatio<N2, D2>> for URatio<N1, D1>
+    where N1: Mul<D2>,
+          N2: Mul<D1>,
+          D1: Mul<D2>,
+          Prod<N1, D2>: Add<Prod<N2, D1>>,
+          URatio<Sum<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>: UnsignedRational,
+{
+    type Output = UReduce<Sum<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>;
+
+    fn add(self, _: URatio<N2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// (N1/D1) - (N2/D2) = (N1*D2 - N2*D1)/(D1*D2), which must not be negative
+impl<N1, D1, N2, D2> Sub<URatio<N2, D2>> for URatio<N1, D1>
+    where N1: Mul<D2>,
+          N2: Mul<D1>,
+          D1: Mul<D2>,
+          Prod<N1, D2>: Sub<Prod<N2, D1>>,
+          URatio<Diff<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>: UnsignedRational,
+{
+    type Output = UReduce<Diff<Prod<N1, D2>, Prod<N2, D1>>, Prod<D1, D2>>;
+
+    fn sub(self, _: URatio<N2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// (N1/D1) * (N2/D2) = (N1*N2)/(D1*D2)
+impl<N1, D1, N2, D2> Mul<URatio<N2, D2>> for URatio<N1, D1>
+    where N1: Mul<N2>,
+          D1: Mul<D2>,
+          URatio<Prod<N1, N2>, Prod<D1, D2>>: UnsignedRational,
+{
+    type Output = UReduce<Prod<N1, N2>, Prod<D1, D2>>;
+
+    fn mul(self, _: URatio<N2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
+
+/// (N1/D1) / (N2/D2) = (N1*D2)/(D1*N2)
+impl<N1, D1, N2, D2> Div<URatio<N2, D2>> for URatio<N1, D1>
+    where N1: Mul<D2>,
+          D1: Mul<N2>,
+          URatio<Prod<N1, D2>, Prod<D1, N2>>: UnsignedRational,
+{
+    type Output = UReduce<Prod<N1, D2>, Prod<D1, N2>>;
+
+    fn div(self, _: URatio<N2, D2>) -> Self::Output {
+        Default::default()
+    }
+}
@@ -0,0 +1,77 @@
+//! Helpers for fitting video content with a compile-time aspect ratio into a container.
+
+use typenum::Integer;
+
+use super::{Rational, private::Positive};
+
+/// The placement of content within a container, as computed by [`fit`].
+///
+/// The bars on either side of the content may differ in size by one pixel when the remaining
+/// space is odd, in which case the extra pixel goes to the right or bottom bar.
+///
+/// [`fit`]: ./fn.fit.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fit {
+    /// The width of the content.
+    pub width: u32,
+    /// The height of the content.
+    pub height: u32,
+    /// The width of the bar to the left of the content.
+    pub left: u32,
+    /// The width of the bar to the right of the content.
+    pub right: u32,
+    /// The height of the bar above the content.
+    pub top: u32,
+    /// The height of the bar below the content.
+    pub bottom: u32,
+}
+
+/// Fits content with aspect ratio `R` (width over height) into a `width` by `height` container.
+///
+/// The content is scaled to be as large as possible without cropping, and both of its dimensions
+/// are rounded down to an even number as required by most chroma-subsampled video formats. All
+/// arithmetic is exact. A non-positive aspect ratio is rejected at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate typenum;
+/// extern crate typenum_ratio;
+///
+/// use typenum::consts::*;
+/// use typenum_ratio::{Ratio, video};
+///
+/// // 4:3 content on a 1080p display is pillarboxed.
+/// let fit = video::fit::<Ratio<P4, P3>>(1920, 1080);
+/// assert_eq!((fit.width, fit.height), (1440, 1080));
+/// assert_eq!((fit.left, fit.right, fit.top, fit.bottom), (240, 240, 0, 0));
+/// ```
+pub fn fit<R>(width: u32, height: u32) -> Fit
+    where R: Rational,
+{
+    const_assert!(Positive<R>);
+
+    let num = R::Num::to_i64() as u128;
+    let den = R::Den::to_i64() as u128;
+    let (w, h) = (u128::from(width), u128::from(height));
+
+    // Compare w/h with num/den to decide which dimension constrains the content.
+    let (content_w, content_h) = if w * den > h * num {
+        (h * num / den, h)
+    } else {
+        (w, w * den / num)
+    };
+
+    let content_w = (content_w as u32) & !1;
+    let content_h = (content_h as u32) & !1;
+
+    let (left, top) = ((width - content_w) / 2, (height - content_h) / 2);
+    Fit {
+        width: content_w,
+        height: content_h,
+        left,
+        right: width - content_w - left,
+        top,
+        bottom: height - content_h - top,
+    }
+}